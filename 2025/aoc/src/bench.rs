@@ -0,0 +1,123 @@
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use common::{
+    Bench, BenchOptions, InputSource, Json, Result, Timing, default_input_path, millis,
+    take_warnings,
+};
+
+use crate::solution;
+
+/// Timings of every benchmarked day
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub example: bool,
+    pub results: Vec<Bench>,
+    /// Days skipped because their input.txt is not there
+    pub missing: Vec<u8>,
+}
+
+impl Report {
+    pub fn to_json(&self, options: BenchOptions) -> Json {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+
+        Json::object([
+            ("year", Json::Int(common::YEAR.into())),
+            ("timestamp", Json::Int(timestamp.into())),
+            ("input", Json::String(self.input().to_string())),
+            ("warmup", Json::Int(options.warmup as i128)),
+            ("iterations", Json::Int(options.iterations as i128)),
+            (
+                "results",
+                Json::Array(self.results.iter().map(Bench::to_json).collect()),
+            ),
+        ])
+    }
+
+    /// Append this run as one JSON line, so the file keeps a history of runs
+    pub fn append_to(&self, path: &Path, options: BenchOptions) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", self.to_json(options))
+    }
+
+    fn input(&self) -> &'static str {
+        if self.example { "example" } else { "real" }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Day  Step    {:>12}  {:>12}  {:>12}",
+            "Min", "Median", "Max"
+        )?;
+        for bench in &self.results {
+            let steps = [
+                ("parse", &bench.parse),
+                ("part 1", &bench.part1),
+                ("part 2", &bench.part2),
+            ];
+            for (step, timing) in steps {
+                writeln!(f, "{:>3}  {:<6}  {}", bench.day, step, Row(timing))?;
+            }
+        }
+
+        if !self.missing.is_empty() {
+            writeln!(f, "Skipped days without input: {:?}", self.missing)?;
+        }
+        Ok(())
+    }
+}
+
+/// Min, median and max columns of a timing
+struct Row<'a>(&'a Timing);
+
+impl fmt::Display for Row<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Row(timing) = self;
+        write!(
+            f,
+            "{:>9.3} ms  {:>9.3} ms  {:>9.3} ms",
+            millis(timing.min),
+            millis(timing.median),
+            millis(timing.max)
+        )
+    }
+}
+
+/// Benchmark each day on its real input, or on its example with `example`
+pub fn bench(days: &[u8], options: BenchOptions, example: bool) -> Result<Report> {
+    let mut report = Report {
+        example,
+        results: Vec::new(),
+        missing: Vec::new(),
+    };
+
+    for &day in days {
+        let Some(bench) = solution(day).map(|solution| solution.bench) else {
+            continue;
+        };
+        let source = if example {
+            InputSource::Example
+        } else {
+            let path = default_input_path(day);
+            if !path.exists() {
+                report.missing.push(day);
+                continue;
+            }
+            InputSource::File(path)
+        };
+
+        report.results.push(bench(&source, options)?);
+        // Warnings are repeated on every iteration and only matter to `run`
+        take_warnings();
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,189 @@
+mod bench;
+mod verify;
+
+use common::{
+    Answer, Bench, BenchOptions, Error, Format, InputSource, Outcome, Part, Result, Solution,
+    default_answers_path, default_input_path,
+};
+use std::path::PathBuf;
+use std::time::Instant;
+
+const USAGE: &str = "\
+Usage: aoc run --day <1-12> --part <1|2> [--input <path> | --input - | --example] [--format <text|json>] [--threads <n>]
+       aoc verify [--day <1-12>] [--answers <path>] [--threads <n>]
+       aoc bench [--day <1-12>] [--warmup <n>] [--iterations <n>] [--example] [--json <path>] [--threads <n>]";
+
+fn usage_and_exit() -> ! {
+    Error::Usage(USAGE.into()).exit()
+}
+
+/// Event days that have a solution
+const DAYS: std::ops::RangeInclusive<u8> = 1..=12;
+
+/// Type-erased entry points into one day's solution
+#[derive(Clone, Copy)]
+struct Day {
+    run: fn(&InputSource, Part) -> Result<Answer>,
+    bench: fn(&InputSource, BenchOptions) -> Result<Bench>,
+}
+
+impl Day {
+    fn of<S: Solution>() -> Day {
+        Day {
+            run: common::run::<S>,
+            bench: common::bench::<S>,
+        }
+    }
+}
+
+/// Dispatch to the requested day's solution
+fn solution(day: u8) -> Option<Day> {
+    let solution = match day {
+        1 => Day::of::<day1::Day1>(),
+        2 => Day::of::<day2::Day2>(),
+        3 => Day::of::<day3::Day3>(),
+        4 => Day::of::<day4::Day4>(),
+        5 => Day::of::<day5::Day5>(),
+        6 => Day::of::<day6::Day6>(),
+        7 => Day::of::<day7::Day7>(),
+        8 => Day::of::<day8::Day8>(),
+        9 => Day::of::<day9::Day9>(),
+        10 => Day::of::<day10::Day10>(),
+        11 => Day::of::<day11::Day11>(),
+        12 => Day::of::<day12::Day12>(),
+        _ => return None,
+    };
+    Some(solution)
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+
+    match args.next().as_deref() {
+        Some("run") => run(args),
+        Some("verify") => verify(args),
+        Some("bench") => bench(args),
+        _ => usage_and_exit(),
+    }
+}
+
+/// Parse a `--day` value, rejecting days without a solution
+fn parse_day(value: &str) -> u8 {
+    match value.parse::<u8>() {
+        Ok(day) if DAYS.contains(&day) => day,
+        _ => Error::Usage(format!(
+            "Invalid day: {}. Use a day between 1 and 12",
+            value
+        ))
+        .exit(),
+    }
+}
+
+/// `aoc run`: solve one part of one day
+fn run(mut args: impl Iterator<Item = String>) {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut format = Format::Text;
+
+    while let Some(arg) = args.next() {
+        if arg == "--example" {
+            input = Some(InputSource::Example);
+            continue;
+        }
+
+        let value = args.next().unwrap_or_else(|| usage_and_exit());
+        match arg.as_str() {
+            "--day" => day = Some(parse_day(&value)),
+            "--format" => format = value.parse().unwrap_or_else(|error: Error| error.exit()),
+            "--part" => part = value.parse::<Part>().ok(),
+            "--input" if value == "-" => input = Some(InputSource::Stdin),
+            "--input" => input = Some(InputSource::File(PathBuf::from(value))),
+            "--threads" => set_threads(&value),
+            _ => usage_and_exit(),
+        }
+    }
+
+    let (Some(day), Some(part)) = (day, part) else {
+        usage_and_exit();
+    };
+    let run = solution(day).unwrap_or_else(|| usage_and_exit()).run;
+    let input = input.unwrap_or_else(|| InputSource::File(default_input_path(day)));
+
+    let start = Instant::now();
+    let answer = run(&input, part).unwrap_or_else(|error| error.exit());
+    Outcome::new(day, part, answer, start.elapsed()).print(format, |answer| {
+        println!("Day {} - Part {}: {}", day, part, answer)
+    });
+}
+
+/// `aoc verify`: check every day's real input against the answers file
+fn verify(mut args: impl Iterator<Item = String>) {
+    let mut days: Vec<u8> = DAYS.collect();
+    let mut answers_path = default_answers_path();
+
+    while let Some(arg) = args.next() {
+        let value = args.next().unwrap_or_else(|| usage_and_exit());
+        match arg.as_str() {
+            "--day" => days = vec![parse_day(&value)],
+            "--answers" => answers_path = PathBuf::from(value),
+            "--threads" => set_threads(&value),
+            _ => usage_and_exit(),
+        }
+    }
+
+    let answers = common::Answers::load(&answers_path).unwrap_or_else(|error| error.exit());
+    let report = verify::verify(&days, &answers);
+    print!("{}", report);
+    if !report.passed() {
+        std::process::exit(1);
+    }
+}
+
+/// `aoc bench`: time parsing and each part of every day
+fn bench(mut args: impl Iterator<Item = String>) {
+    let mut days: Vec<u8> = DAYS.collect();
+    let mut options = BenchOptions::default();
+    let mut example = false;
+    let mut history = None;
+
+    while let Some(arg) = args.next() {
+        if arg == "--example" {
+            example = true;
+            continue;
+        }
+
+        let value = args.next().unwrap_or_else(|| usage_and_exit());
+        match arg.as_str() {
+            "--day" => days = vec![parse_day(&value)],
+            "--warmup" => options.warmup = parse_count(&value, 0),
+            "--iterations" => options.iterations = parse_count(&value, 1),
+            "--json" => history = Some(PathBuf::from(value)),
+            "--threads" => set_threads(&value),
+            _ => usage_and_exit(),
+        }
+    }
+
+    let report = bench::bench(&days, options, example).unwrap_or_else(|error| error.exit());
+    print!("{}", report);
+
+    if let Some(path) = history
+        && let Err(error) = report.append_to(&path, options)
+    {
+        eprintln!("Error writing {}: {}", path.display(), error);
+        std::process::exit(3);
+    }
+}
+
+/// Parse a repetition count of at least `min`
+fn parse_count(value: &str, min: usize) -> usize {
+    match value.parse::<usize>() {
+        Ok(n) if n >= min => n,
+        _ => Error::Usage(format!("Invalid count: {}. Use at least {}", value, min)).exit(),
+    }
+}
+
+/// Size the thread pool of the days that solve in parallel
+fn set_threads(value: &str) {
+    common::set_threads(parse_count(value, 1)).unwrap_or_else(|error| error.exit());
+}
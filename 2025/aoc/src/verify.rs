@@ -0,0 +1,155 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use common::{Answers, InputSource, Part, default_input_path, millis, take_warnings};
+
+use crate::solution;
+
+/// How one part's answer compares to the answers file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// No accepted answer has been recorded yet
+    Unknown,
+    /// The day's input.txt is not there, so nothing was run
+    NoInput,
+    /// Reading, parsing or solving failed
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Unknown => "unknown",
+            Status::NoInput => "no input",
+            Status::Error => "ERROR",
+        };
+        // `pad` honours the width used to line up the table
+        f.pad(status)
+    }
+}
+
+/// Outcome of solving one part of one day
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+    /// The answer, or the error message when solving failed
+    pub output: String,
+    pub elapsed: Duration,
+    /// Caveats the solver raised about its answer
+    pub warnings: Vec<String>,
+}
+
+/// Every check of a `verify` run, displayed as a table
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    /// No part gave a wrong answer or failed to run
+    pub fn passed(&self) -> bool {
+        self.checks
+            .iter()
+            .all(|check| !matches!(check.status, Status::Fail { .. } | Status::Error))
+    }
+
+    fn count(&self, matches: impl Fn(&Status) -> bool) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| matches(&check.status))
+            .count()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Day  Part  Status    {:>12}  Answer", "Time")?;
+        for check in &self.checks {
+            write!(
+                f,
+                "{:>3}  {:>4}  {:<8}  {:>9.3} ms  {}",
+                check.day,
+                check.part,
+                check.status,
+                millis(check.elapsed),
+                check.output
+            )?;
+            if let Status::Fail { expected } = &check.status {
+                write!(f, " (expected {})", expected)?;
+            }
+            writeln!(f)?;
+            for warning in &check.warnings {
+                writeln!(f, "{:>20}warning: {}", "", warning)?;
+            }
+        }
+
+        let total: Duration = self.checks.iter().map(|check| check.elapsed).sum();
+        writeln!(
+            f,
+            "{} passed, {} failed, {} unknown, {} without input in {:.3} ms",
+            self.count(|status| *status == Status::Pass),
+            self.count(|status| matches!(status, Status::Fail { .. } | Status::Error)),
+            self.count(|status| *status == Status::Unknown),
+            self.count(|status| *status == Status::NoInput),
+            millis(total)
+        )
+    }
+}
+
+/// Solve both parts of each day from its input.txt and compare to `answers`
+pub fn verify(days: &[u8], answers: &Answers) -> Report {
+    let mut checks = Vec::new();
+
+    for &day in days {
+        let Some(run) = solution(day).map(|solution| solution.run) else {
+            continue;
+        };
+        let path = default_input_path(day);
+
+        for part in [Part::One, Part::Two] {
+            let expected = answers.get(day, part);
+            let mut check = Check {
+                day,
+                part,
+                status: Status::NoInput,
+                output: String::new(),
+                elapsed: Duration::ZERO,
+                warnings: Vec::new(),
+            };
+
+            if path.exists() {
+                let start = Instant::now();
+                let result = run(&InputSource::File(path.clone()), part);
+                check.elapsed = start.elapsed();
+                check.warnings = take_warnings();
+
+                (check.status, check.output) = match result {
+                    Err(error) => (Status::Error, error.to_string()),
+                    Ok(answer) => {
+                        let answer = answer.to_string();
+                        let status = match expected {
+                            None => Status::Unknown,
+                            Some(expected) if expected == answer => Status::Pass,
+                            Some(expected) => Status::Fail {
+                                expected: expected.to_string(),
+                            },
+                        };
+                        (status, answer)
+                    }
+                };
+            }
+
+            checks.push(check);
+        }
+    }
+
+    Report { checks }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::input::workspace_dir;
+use crate::{Error, InputSource, Part, Result, Solution, default_input_path, run};
+
+/// Accepted answers for the real puzzle inputs, recorded as
+///
+/// ```toml
+/// [day1]
+/// part1 = 1234
+/// part2 = "text answers are quoted"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    accepted: HashMap<(u8, Part), String>,
+}
+
+impl Answers {
+    /// Read the answers file at `path`
+    pub fn load(path: &Path) -> Result<Answers> {
+        let text = fs::read_to_string(path).map_err(|error| Error::Io {
+            source: path.display().to_string(),
+            error,
+        })?;
+        Answers::parse(&text)
+    }
+
+    /// Parse the contents of an answers file
+    pub fn parse(text: &str) -> Result<Answers> {
+        let table: toml::Table = text.parse().map_err(|error: toml::de::Error| {
+            let start = error.span().map_or(0, |span| span.start).min(text.len());
+            let start = text.floor_char_boundary(start);
+            Error::parse_at(text, &text[start..], error.message())
+        })?;
+
+        let mut accepted = HashMap::new();
+        for (section, parts) in &table {
+            let day = section
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| key_error(text, section, "expected a [dayN] section"))?;
+            let Some(parts) = parts.as_table() else {
+                return Err(key_error(text, section, "expected a [dayN] section"));
+            };
+
+            for (key, value) in parts {
+                let part = match key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(key_error(text, key, "expected part1 or part2")),
+                };
+                let answer = match value {
+                    toml::Value::Integer(n) => n.to_string(),
+                    toml::Value::String(s) => s.clone(),
+                    _ => return Err(key_error(text, key, "answer must be a number or a string")),
+                };
+                accepted.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers { accepted })
+    }
+
+    /// The accepted answer for `day` and `part`, if one was recorded
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.accepted.get(&(day, part)).map(String::as_str)
+    }
+}
+
+/// The toml table drops spans, so point at the key's first appearance instead
+fn key_error(text: &str, key: &str, message: &str) -> Error {
+    let token = text.find(key).map_or(text, |start| &text[start..]);
+    Error::parse_at(text, token, format!("'{}': {}", key, message))
+}
+
+/// The year's answers file, next to the workspace Cargo.toml
+pub fn default_answers_path() -> PathBuf {
+    workspace_dir().join("answers.toml")
+}
+
+/// Check both parts of a day against the real input and the answers file.
+///
+/// Meant for the opt-in tests: a missing input, answers file or answer is
+/// reported and skipped, while a wrong answer or failure panics.
+pub fn assert_real_input<S: Solution>() {
+    let input = default_input_path(S::DAY);
+    if !input.exists() {
+        eprintln!("skipping day {}: no {}", S::DAY, input.display());
+        return;
+    }
+    let answers_path = default_answers_path();
+    if !answers_path.exists() {
+        eprintln!("skipping day {}: no {}", S::DAY, answers_path.display());
+        return;
+    }
+    let answers = Answers::load(&answers_path).unwrap_or_else(|error| panic!("{}", error));
+
+    for part in [Part::One, Part::Two] {
+        let Some(expected) = answers.get(S::DAY, part) else {
+            eprintln!("skipping day {} part {}: no recorded answer", S::DAY, part);
+            continue;
+        };
+        let answer = run::<S>(&InputSource::File(input.clone()), part)
+            .unwrap_or_else(|error| panic!("day {} part {}: {}", S::DAY, part, error));
+        assert_eq!(
+            answer.to_string(),
+            expected,
+            "day {} part {} does not match the recorded answer",
+            S::DAY,
+            part
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers_and_text() {
+        let answers = Answers::parse("[day1]\npart1 = 42\n\n[day12]\npart2 = \"done\"\n").unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("42"));
+        assert_eq!(answers.get(1, Part::Two), None);
+        assert_eq!(answers.get(12, Part::Two), Some("done"));
+    }
+
+    fn position(text: &str) -> (usize, usize) {
+        match Answers::parse(text) {
+            Err(Error::Parse { line, column, .. }) => (line, column),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert_eq!(position("[day1]\npart3 = 1\n"), (2, 1));
+        assert_eq!(position("[dayone]\npart1 = 1\n"), (1, 2));
+    }
+
+    #[test]
+    fn reports_toml_syntax_errors() {
+        assert_eq!(position("[day1]\npart1 = \n").0, 2);
+    }
+}
@@ -0,0 +1,152 @@
+use std::time::{Duration, Instant};
+
+use crate::{Answer, InputSource, Json, Part, Result, Solution, answer};
+
+/// How many untimed and timed runs to make of each step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            iterations: 10,
+        }
+    }
+}
+
+/// Spread of the timed runs of one step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Timing {
+        samples.sort();
+        Timing {
+            min: samples.first().copied().unwrap_or_default(),
+            median: samples.get(samples.len() / 2).copied().unwrap_or_default(),
+            max: samples.last().copied().unwrap_or_default(),
+        }
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("min_ms", Json::Float(millis(self.min))),
+            ("median_ms", Json::Float(millis(self.median))),
+            ("max_ms", Json::Float(millis(self.max))),
+        ])
+    }
+}
+
+/// Timings of one day, parsing and each part measured separately
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bench {
+    pub day: u8,
+    pub parse: Timing,
+    pub part1: Timing,
+    pub part2: Timing,
+    /// Answers from the last run, to spot a benchmark of a broken solution
+    pub answers: [Answer; 2],
+}
+
+impl Bench {
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("day", Json::Int(self.day.into())),
+            ("parse", self.parse.to_json()),
+            ("part1", self.part1.to_json()),
+            ("part2", self.part2.to_json()),
+            (
+                "answers",
+                Json::Array(self.answers.iter().map(Json::from).collect()),
+            ),
+        ])
+    }
+}
+
+/// Fractional milliseconds, the unit every report uses
+pub fn millis(duration: Duration) -> f64 {
+    // Dividing whole nanoseconds keeps the printed decimals short
+    duration.as_nanos() as f64 / 1_000_000.0
+}
+
+/// Time parsing and both parts of a day on the input from `source`
+pub fn bench<S: Solution>(source: &InputSource, options: BenchOptions) -> Result<Bench> {
+    let text = source.read::<S>(Part::One)?;
+    let (parse, input) = time(options, || source.parse_text::<S>(&text))?;
+    let (part1, answer1) = time(options, || answer::<S>(&input, Part::One))?;
+
+    // Only days with a separate part 2 example need their input parsed again
+    let text2 = source.read::<S>(Part::Two)?;
+    let input2 = if text2 == text {
+        input
+    } else {
+        source.parse_text::<S>(&text2)?
+    };
+    let (part2, answer2) = time(options, || answer::<S>(&input2, Part::Two))?;
+
+    Ok(Bench {
+        day: S::DAY,
+        parse,
+        part1,
+        part2,
+        answers: [answer1, answer2],
+    })
+}
+
+/// Run `step` for the warmup, then time each iteration, keeping the last result
+fn time<T>(options: BenchOptions, mut step: impl FnMut() -> Result<T>) -> Result<(Timing, T)> {
+    for _ in 0..options.warmup {
+        step()?;
+    }
+
+    let start = Instant::now();
+    let mut value = step()?;
+    let mut samples = vec![start.elapsed()];
+
+    for _ in 1..options.iterations {
+        let start = Instant::now();
+        let next = step()?;
+        samples.push(start.elapsed());
+        // Dropped outside the timed region
+        value = next;
+    }
+
+    Ok((Timing::from_samples(samples), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_samples() {
+        let ms = Duration::from_millis;
+        let timing = Timing::from_samples(vec![ms(5), ms(1), ms(9), ms(3), ms(4)]);
+        assert_eq!(timing.min, ms(1));
+        assert_eq!(timing.median, ms(4));
+        assert_eq!(timing.max, ms(9));
+    }
+
+    #[test]
+    fn runs_every_iteration() {
+        let options = BenchOptions {
+            warmup: 2,
+            iterations: 5,
+        };
+        let mut runs = 0;
+        let (_, last) = time(options, || {
+            runs += 1;
+            Ok(runs)
+        })
+        .unwrap();
+        assert_eq!((runs, last), (7, 7));
+    }
+}
@@ -0,0 +1,265 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Instant;
+
+use crate::{
+    Answer, Error, Format, InputSource, Outcome, Part, Result, Solution, answer,
+    default_input_path, run, set_threads,
+};
+
+/// An option accepted by only some days' binaries, on top of the shared ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayOption {
+    pub name: &'static str,
+    /// Placeholder shown in the usage for the option's value, `None` for a flag
+    pub value: Option<&'static str>,
+}
+
+impl DayOption {
+    /// `--name` on its own
+    pub const fn flag(name: &'static str) -> DayOption {
+        DayOption { name, value: None }
+    }
+
+    /// `--threads <n>`, for days that solve in parallel: sizes the thread pool
+    pub const THREADS: DayOption = DayOption::value("--threads", "n");
+
+    /// `--name <placeholder>`
+    pub const fn value(name: &'static str, placeholder: &'static str) -> DayOption {
+        DayOption {
+            name,
+            value: Some(placeholder),
+        }
+    }
+}
+
+/// Command line shared by every day's binary:
+/// `<part1|part2> [--input <path> | -] [--example] [--format <text|json>]`,
+/// plus the day's own options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args {
+    pub part: Part,
+    pub input: InputSource,
+    pub format: Format,
+    /// Day specific options given, with their value if they take one
+    pub options: Vec<(&'static str, Option<String>)>,
+}
+
+impl Args {
+    /// Parse the process arguments, printing usage and exiting on error
+    pub fn from_env<S: Solution>() -> Args {
+        Args::from_env_with::<S>(&[])
+    }
+
+    /// Parse the process arguments, also accepting the day's own `options`.
+    /// Sets up the thread pool when [`DayOption::THREADS`] is given.
+    pub fn from_env_with<S: Solution>(options: &[DayOption]) -> Args {
+        let mut args = std::env::args();
+        let program = args.next().unwrap_or_else(|| format!("day{}", S::DAY));
+
+        let args = Args::parse_with::<S>(args, options).unwrap_or_else(|error| {
+            let mut usage = format!(
+                "{} <part1|part2> [--input <path> | -] [--example] [--format <text|json>]",
+                program
+            );
+            for option in options {
+                match option.value {
+                    Some(placeholder) => usage += &format!(" [{} <{}>]", option.name, placeholder),
+                    None => usage += &format!(" [{}]", option.name),
+                }
+            }
+            Error::Usage(format!("{}\nUsage: {}", error, usage)).exit()
+        });
+
+        let threads = args.value_as::<usize>(DayOption::THREADS.name);
+        if let Some(threads) = threads.unwrap_or_else(|error| error.exit()) {
+            set_threads(threads).unwrap_or_else(|error| error.exit());
+        }
+        args
+    }
+
+    /// Parse arguments (without the program name)
+    pub fn parse<S: Solution>(args: impl IntoIterator<Item = String>) -> Result<Args> {
+        Args::parse_with::<S>(args, &[])
+    }
+
+    /// Parse arguments (without the program name), also accepting `options`
+    pub fn parse_with<S: Solution>(
+        args: impl IntoIterator<Item = String>,
+        options: &[DayOption],
+    ) -> Result<Args> {
+        let mut part = None;
+        let mut input = None;
+        let mut format = Format::Text;
+        let mut given = Vec::new();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if let Some(option) = options.iter().find(|option| option.name == arg) {
+                let value = match option.value {
+                    Some(_) => Some(args.next().ok_or_else(|| {
+                        Error::Usage(format!("Missing value for {}", option.name))
+                    })?),
+                    None => None,
+                };
+                given.push((option.name, value));
+                continue;
+            }
+
+            let source = match arg.as_str() {
+                "--format" => {
+                    let value = args.next();
+                    let value = value
+                        .ok_or_else(|| Error::Usage("Missing value for --format".to_string()))?;
+                    format = value.parse()?;
+                    continue;
+                }
+                "--input" => match args.next() {
+                    Some(path) if path == "-" => InputSource::Stdin,
+                    Some(path) => InputSource::File(PathBuf::from(path)),
+                    None => return Err(Error::Usage("Missing value for --input".to_string())),
+                },
+                "-" => InputSource::Stdin,
+                "--example" => InputSource::Example,
+                _ if part.is_none() => {
+                    part = Some(arg.parse::<Part>()?);
+                    continue;
+                }
+                _ => return Err(Error::Usage(format!("Unexpected argument: {}", arg))),
+            };
+
+            if input.replace(source).is_some() {
+                return Err(Error::Usage(
+                    "Only one of --input, - and --example can be given".to_string(),
+                ));
+            }
+        }
+
+        Ok(Args {
+            part: part.ok_or_else(|| Error::Usage("Missing part".to_string()))?,
+            input: input.unwrap_or_else(|| InputSource::File(default_input_path(S::DAY))),
+            format,
+            options: given,
+        })
+    }
+
+    /// Whether the day specific option `name` was given
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.iter().any(|(given, _)| *given == name)
+    }
+
+    /// Value of the day specific option `name`, the last one if repeated
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(given, _)| *given == name)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    /// Value of the day specific option `name` parsed as a `T`
+    pub fn value_as<T: FromStr>(&self, name: &str) -> Result<Option<T>> {
+        self.value(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| Error::Usage(format!("Invalid value for {}: {}", name, value)))
+            })
+            .transpose()
+    }
+
+    /// Read, parse and solve the requested part
+    pub fn solve<S: Solution>(&self) -> Result<Answer> {
+        run::<S>(&self.input, self.part)
+    }
+
+    /// Solve the requested part and print it in the requested format, using
+    /// `text` for the day's own message. Exits on error.
+    pub fn report<S: Solution>(&self, text: impl FnOnce(&Answer)) {
+        self.report_with::<S>(|input| answer::<S>(input, self.part), text);
+    }
+
+    /// [`Args::report`] with the day's own `solve` for the parsed input, for
+    /// options the [`Solution`] parts do not take
+    pub fn report_with<S: Solution>(
+        &self,
+        solve: impl FnOnce(&S::Input) -> Result<Answer>,
+        text: impl FnOnce(&Answer),
+    ) {
+        let start = Instant::now();
+        let answer = self
+            .parse_input::<S>()
+            .and_then(|input| solve(&input))
+            .unwrap_or_else(|error| error.exit());
+        Outcome::new(S::DAY, self.part, answer, start.elapsed()).print(self.format, text);
+    }
+
+    /// Read and parse the requested input
+    pub fn parse_input<S: Solution>(&self) -> Result<S::Input> {
+        self.input.parse::<S>(self.part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    struct Day;
+
+    impl Solution for Day {
+        const DAY: u8 = 1;
+        const EXAMPLE: &'static str = "";
+        type Input = ();
+
+        fn parse(_: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> Result<Answer> {
+            Ok(Answer::from(0))
+        }
+
+        fn part2(_: &()) -> Result<Answer> {
+            Ok(Answer::from(0))
+        }
+    }
+
+    fn parse(args: &[&str], options: &[DayOption]) -> Result<Args> {
+        Args::parse_with::<Day>(args.iter().map(|arg| arg.to_string()), options)
+    }
+
+    #[test]
+    fn accepts_day_options() {
+        let options = [
+            DayOption::flag("--explain"),
+            DayOption::value("--threads", "n"),
+        ];
+        let args = parse(&["--explain", "part2", "--threads", "4"], &options).unwrap();
+        assert_eq!(args.part, Part::Two);
+        assert!(args.flag("--explain"));
+        assert_eq!(args.value_as::<usize>("--threads").unwrap(), Some(4));
+
+        let args = parse(&["part1"], &options).unwrap();
+        assert!(!args.flag("--explain"));
+        assert_eq!(args.value("--threads"), None);
+    }
+
+    #[test]
+    fn rejects_options_of_other_days() {
+        assert!(matches!(
+            parse(&["part1", "--explain"], &[]),
+            Err(Error::Usage(_))
+        ));
+        let options = [DayOption::value("--threads", "n")];
+        assert!(matches!(
+            parse(&["part1", "--threads"], &options),
+            Err(Error::Usage(_))
+        ));
+        let args = parse(&["part1", "--threads", "many"], &options).unwrap();
+        assert!(matches!(
+            args.value_as::<usize>("--threads"),
+            Err(Error::Usage(_))
+        ));
+    }
+}
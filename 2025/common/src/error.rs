@@ -0,0 +1,139 @@
+use std::fmt;
+use std::io;
+use std::process;
+
+/// Everything that can go wrong between the command line and an answer
+#[derive(Debug)]
+pub enum Error {
+    /// Invalid command line arguments
+    Usage(String),
+    /// The puzzle input could not be read
+    Io { source: String, error: io::Error },
+    /// The puzzle input is malformed; `line` and `column` are 1-based
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well formed but has no answer
+    Unsolvable(String),
+    /// The answer, or the search for it, is beyond the solver's numbers or
+    /// limits; the puzzle may well have a solution
+    Overflow(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Error {
+        Error::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// A parse error located at `token`, which must be a slice of `input`
+    pub fn parse_at(input: &str, token: &str, message: impl Into<String>) -> Error {
+        let (line, column) = locate(input, token);
+        Error::parse(line, column, message)
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Error {
+        Error::Unsolvable(message.into())
+    }
+
+    pub fn overflow(message: impl Into<String>) -> Error {
+        Error::Overflow(message.into())
+    }
+
+    /// Process exit status for this class of failure
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Usage(_) => 2,
+            Error::Io { .. } => 3,
+            Error::Parse { .. } => 4,
+            Error::Unsolvable(_) => 5,
+            Error::Overflow(_) => 6,
+        }
+    }
+
+    /// Report the error on stderr and exit with its status code
+    pub fn exit(&self) -> ! {
+        eprintln!("{}", self);
+        process::exit(self.exit_code());
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Usage(message) => write!(f, "{}", message),
+            Error::Io { source, error } => write!(f, "Error reading {}: {}", source, error),
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Parse error at line {}, column {}: {}",
+                line, column, message
+            ),
+            Error::Unsolvable(message) => write!(f, "No solution: {}", message),
+            Error::Overflow(message) => write!(f, "Too large to solve: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// 1-based (line, column) of `token` within `input`, where `token` is a
+/// slice borrowed from `input`. Columns count characters, not bytes.
+pub fn locate(input: &str, token: &str) -> (usize, usize) {
+    let offset = (token.as_ptr() as usize)
+        .saturating_sub(input.as_ptr() as usize)
+        .min(input.len());
+    let before = &input[..input.floor_char_boundary(offset)];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_tokens_by_line_and_character() {
+        let input = "L68\nR4x\nµ12";
+        assert_eq!(locate(input, &input[..3]), (1, 1));
+        assert_eq!(locate(input, &input[5..]), (2, 2));
+        assert_eq!(locate(input, &input[10..]), (3, 2));
+    }
+
+    #[test]
+    fn exit_codes_differ_per_class() {
+        let errors = [
+            Error::Usage(String::new()),
+            Error::Io {
+                source: String::new(),
+                error: io::ErrorKind::NotFound.into(),
+            },
+            Error::parse(1, 1, ""),
+            Error::unsolvable(""),
+            Error::overflow(""),
+        ];
+        let mut codes: Vec<i32> = errors.iter().map(Error::exit_code).collect();
+        codes.dedup();
+        assert_eq!(codes.len(), errors.len());
+        assert!(codes.iter().all(|&code| code > 1));
+    }
+}
@@ -0,0 +1,293 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Error, Result};
+
+/// A cell of a grid, counted from the top left
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub const fn new(row: usize, col: usize) -> Pos {
+        Pos { row, col }
+    }
+
+    /// The cell `rows` down and `cols` right, `None` above or left of the grid
+    pub fn offset(self, rows: isize, cols: isize) -> Option<Pos> {
+        Some(Pos {
+            row: self.row.checked_add_signed(rows)?,
+            col: self.col.checked_add_signed(cols)?,
+        })
+    }
+}
+
+/// Steps to the cells sharing an edge: up, left, right, down
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Steps to the cells sharing an edge or a corner, row by row
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// What [`Grid::parse`] does with lines shorter than the longest one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ragged<T> {
+    /// Report them as parse errors
+    Reject,
+    /// Fill them up with this cell
+    Pad(T),
+}
+
+/// A rectangle of cells stored row after row in one buffer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// One row per line of `input`, converting each character with `cell`,
+    /// which returns `None` for characters that do not belong. Blank lines
+    /// are skipped when ragged lines are rejected, and padded like any other
+    /// short line otherwise.
+    pub fn parse(
+        input: &str,
+        ragged: Ragged<T>,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>> {
+        let keep_blank = matches!(ragged, Ragged::Pad(_));
+        let mut rows: Vec<(&str, Vec<T>)> = Vec::new();
+        for line in input.lines().filter(|line| keep_blank || !line.is_empty()) {
+            let mut row = Vec::new();
+            for (i, c) in line.char_indices() {
+                let Some(value) = cell(c) else {
+                    return Err(Error::parse_at(
+                        input,
+                        &line[i..],
+                        format!("unexpected character '{}'", c),
+                    ));
+                };
+                row.push(value);
+            }
+            rows.push((line, row));
+        }
+
+        let width = rows.iter().map(|(_, row)| row.len()).max().unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (line, mut row) in rows {
+            if row.len() < width {
+                match &ragged {
+                    Ragged::Reject => {
+                        return Err(Error::parse_at(
+                            input,
+                            line,
+                            format!("expected {} cells, found {}", width, row.len()),
+                        ));
+                    }
+                    Ragged::Pad(fill) => row.resize(width, fill.clone()),
+                }
+            }
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// The grid with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.row * self.width + pos.col])
+        } else {
+            None
+        }
+    }
+
+    /// The cells of row `row`, left to right
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::new(row, col)))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, that matches `predicate`
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// The cells `steps` away from `pos` that are inside the grid
+    pub fn neighbours<'a>(
+        &'a self,
+        pos: Pos,
+        steps: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        steps
+            .iter()
+            .filter_map(move |&(rows, cols)| pos.offset(rows, cols))
+            .filter(|&next| self.contains(next))
+    }
+
+    /// The up to 4 cells sharing an edge with `pos`
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &ORTHOGONAL)
+    }
+
+    /// The up to 8 cells sharing an edge or a corner with `pos`
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &ADJACENT)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        &self.cells[pos.row * self.width + pos.col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        &mut self.cells[pos.row * self.width + pos.col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    /// One line per row, as the grid would be written in a puzzle
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.height {
+            for cell in self.row(row) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_prints_back() {
+        let input = "#.#\n..#\n";
+        let grid = Grid::parse(input, Ragged::Reject, Some).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(1, 2)], '#');
+        assert_eq!(grid.to_string(), input);
+        assert_eq!(grid.find(|&c| c == '.'), Some(Pos::new(0, 1)));
+    }
+
+    #[test]
+    fn handles_ragged_lines() {
+        let input = "ab\na\n";
+        let Err(Error::Parse { line, message, .. }) = Grid::parse(input, Ragged::Reject, Some)
+        else {
+            panic!("ragged lines were accepted");
+        };
+        assert_eq!((line, message.as_str()), (2, "expected 2 cells, found 1"));
+
+        let padded = Grid::parse(input, Ragged::Pad(' '), Some).unwrap();
+        assert_eq!(padded.to_string(), "ab\na \n");
+
+        let blank = "ab\n\na\n";
+        let skipped = Grid::parse("ab\n\nab\n", Ragged::Reject, Some).unwrap();
+        assert_eq!(skipped.height(), 2);
+        let padded = Grid::parse(blank, Ragged::Pad('.'), Some).unwrap();
+        assert_eq!(padded.to_string(), "ab\n..\na.\n");
+
+        let bits = Grid::parse(".#\nx.\n", Ragged::Reject, |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        });
+        assert!(matches!(
+            bits,
+            Err(Error::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = Grid::new(3, 2, 0);
+        let corner: Vec<Pos> = grid.neighbours4(Pos::new(0, 0)).collect();
+        assert_eq!(corner, vec![Pos::new(0, 1), Pos::new(1, 0)]);
+        assert_eq!(grid.neighbours8(Pos::new(0, 1)).count(), 5);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 5);
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+        assert_eq!(grid.positions().last(), Some(Pos::new(1, 2)));
+    }
+}
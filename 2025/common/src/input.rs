@@ -0,0 +1,78 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::{Answer, Error, Part, Result, Solution, answer, example};
+
+/// Where a day's puzzle input comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    /// The sample input bundled with the day's solution
+    Example,
+}
+
+impl InputSource {
+    /// Read the raw puzzle text for `part`
+    pub fn read<S: Solution>(&self, part: Part) -> Result<String> {
+        let read = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            InputSource::Example => Ok(example::<S>(part).to_string()),
+        };
+
+        read.map_err(|error| Error::Io {
+            source: self.to_string(),
+            error,
+        })
+    }
+
+    /// Read and parse the puzzle input for `part`
+    pub fn parse<S: Solution>(&self, part: Part) -> Result<S::Input> {
+        self.parse_text::<S>(&self.read::<S>(part)?)
+    }
+
+    /// Parse text read from this source
+    pub fn parse_text<S: Solution>(&self, input: &str) -> Result<S::Input> {
+        match self {
+            InputSource::Example => S::parse_example(input),
+            _ => S::parse(input),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Example => write!(f, "example input"),
+        }
+    }
+}
+
+/// Root of the year's workspace, found from this crate's manifest so it does
+/// not depend on the directory the binary is run from
+pub(crate) fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or_else(|| Path::new(".."))
+}
+
+/// Each day keeps its puzzle input next to its Cargo.toml
+pub fn default_input_path(day: u8) -> PathBuf {
+    workspace_dir()
+        .join(format!("day{}", day))
+        .join("input.txt")
+}
+
+/// Read, parse and solve the requested part
+pub fn run<S: Solution>(source: &InputSource, part: Part) -> Result<Answer> {
+    let input = source.parse::<S>(part)?;
+    answer::<S>(&input, part)
+}
@@ -0,0 +1,30 @@
+//! Shared building blocks for the Advent of Code 2025 solutions.
+
+mod answer;
+mod answers;
+mod bench;
+mod cli;
+mod error;
+mod grid;
+mod input;
+mod json;
+mod output;
+mod random;
+mod solution;
+mod threads;
+
+pub use answer::Answer;
+pub use answers::{Answers, assert_real_input, default_answers_path};
+pub use bench::{Bench, BenchOptions, Timing, bench, millis};
+pub use cli::{Args, DayOption};
+pub use error::{Error, Result, locate};
+pub use grid::{ADJACENT, Grid, ORTHOGONAL, Pos, Ragged};
+pub use input::{InputSource, default_input_path, run};
+pub use json::Json;
+pub use output::{Format, Outcome, take_warnings, warn};
+pub use random::Random;
+pub use solution::{Part, Solution, answer, example, solve};
+pub use threads::set_threads;
+
+/// Every crate in this workspace solves puzzles from this event
+pub const YEAR: u16 = 2025;
@@ -0,0 +1,124 @@
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::{Answer, Error, Json, Part, YEAR, millis};
+
+/// How a solved answer is printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Each day's own message, for people
+    #[default]
+    Text,
+    /// One JSON object with the same fields for every day, for scripts
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(Error::Usage(format!(
+                "Invalid format: {}. Use 'text' or 'json'",
+                s
+            ))),
+        }
+    }
+}
+
+static WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Record a caveat about the answer being computed, such as input that was
+/// skipped or a search cut short by its time budget
+pub fn warn(message: impl Into<String>) {
+    WARNINGS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(message.into());
+}
+
+/// Warnings recorded since the last call
+pub fn take_warnings() -> Vec<String> {
+    std::mem::take(
+        &mut *WARNINGS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    )
+}
+
+/// A solved part with how long it took and any warnings raised on the way
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    pub warnings: Vec<String>,
+}
+
+impl Outcome {
+    /// Collect the warnings raised while solving `part` of `day`
+    pub fn new(day: u8, part: Part, answer: Answer, elapsed: Duration) -> Outcome {
+        Outcome {
+            day,
+            part,
+            answer,
+            elapsed,
+            warnings: take_warnings(),
+        }
+    }
+
+    pub fn to_json(&self) -> Json {
+        let part = match self.part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+        Json::object([
+            ("year", Json::Int(YEAR.into())),
+            ("day", Json::Int(self.day.into())),
+            ("part", Json::Int(part)),
+            ("answer", Json::from(&self.answer)),
+            ("elapsed_ms", Json::Float(millis(self.elapsed))),
+            (
+                "warnings",
+                Json::Array(self.warnings.iter().cloned().map(Json::String).collect()),
+            ),
+        ])
+    }
+
+    /// Print the answer as JSON, or with `text` followed by the warnings on stderr
+    pub fn print(&self, format: Format, text: impl FnOnce(&Answer)) {
+        match format {
+            Format::Text => {
+                text(&self.answer);
+                for warning in &self.warnings {
+                    eprintln!("Warning: {}", warning);
+                }
+            }
+            Format::Json => println!("{}", self.to_json()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_has_every_field() {
+        let outcome = Outcome {
+            day: 9,
+            part: Part::Two,
+            answer: Answer::from(24),
+            elapsed: Duration::from_micros(1500),
+            warnings: vec!["sampled".to_string()],
+        };
+        assert_eq!(
+            outcome.to_json().to_string(),
+            r#"{"year":2025,"day":9,"part":2,"answer":24,"elapsed_ms":1.5,"warnings":["sampled"]}"#
+        );
+    }
+}
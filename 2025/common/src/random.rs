@@ -0,0 +1,44 @@
+/// Reproducible pseudo-random numbers for property tests: the same sequence
+/// on every run, so a failing case can be replayed
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Default for Random {
+    fn default() -> Random {
+        Random::new(0x2025)
+    }
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        Random { state: seed }
+    }
+
+    /// A number in `0..n`, from a 64 bit linear congruential generator
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.state >> 33) % n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replays_the_same_numbers_in_range() {
+        let numbers: Vec<u64> = {
+            let mut rng = Random::default();
+            (0..100).map(|_| rng.below(7)).collect()
+        };
+        let mut rng = Random::default();
+        assert!(numbers.iter().all(|&n| n == rng.below(7)));
+        assert!(numbers.iter().all(|&n| n < 7));
+        assert!((0..7).all(|n| numbers.contains(&n)));
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Answer, Error, Result};
+
+/// One of the two halves of a daily puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    /// Accept both `2` and `part2`, like the per-day binaries
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim_start_matches("part") {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(Error::Usage(format!(
+                "Invalid part: {}. Use 'part1' or 'part2'",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// A day's puzzle: parse the input once, then answer either part from it
+pub trait Solution {
+    /// Day of the event this solves (1-12)
+    const DAY: u8;
+
+    /// Sample input from the puzzle description
+    const EXAMPLE: &'static str;
+
+    /// Sample input for part 2, when the puzzle gives a different one
+    const EXAMPLE_PART2: &'static str = Self::EXAMPLE;
+
+    /// Parsed puzzle input shared by both parts
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parse the sample input, for days whose example uses different
+    /// parameters than the real puzzle
+    fn parse_example(input: &str) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+
+    fn part2(input: &Self::Input) -> Result<Answer>;
+}
+
+/// Solve the requested part from already parsed input
+pub fn answer<S: Solution>(input: &S::Input, part: Part) -> Result<Answer> {
+    match part {
+        Part::One => S::part1(input),
+        Part::Two => S::part2(input),
+    }
+}
+
+/// Parse `input` and solve the requested part
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<Answer> {
+    answer::<S>(&S::parse(input)?, part)
+}
+
+/// The bundled sample input for `part`
+pub fn example<S: Solution>(part: Part) -> &'static str {
+    match part {
+        Part::One => S::EXAMPLE,
+        Part::Two => S::EXAMPLE_PART2,
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{Error, Result};
+
+/// Solve in parallel on `n` threads instead of one per core. Only takes
+/// effect before the first parallel solve.
+pub fn set_threads(n: usize) -> Result<()> {
+    if n == 0 {
+        return Err(Error::Usage(
+            "Invalid thread count: 0. Use at least 1".to_string(),
+        ));
+    }
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(n)
+        .build_global()
+        .map_err(|error| Error::Usage(format!("Cannot start {} threads: {}", n, error)))
+}
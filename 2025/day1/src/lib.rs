@@ -0,0 +1,348 @@
+//! Day 1: Secret Entrance
+
+use common::{Answer, Error, Result, Solution};
+use std::fmt;
+
+/// Which way a rotation turns the dial
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Towards lower numbers
+    Left,
+    /// Towards higher numbers
+    Right,
+}
+
+/// One line of the document: a direction and a number of clicks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub distance: i64,
+}
+
+impl fmt::Display for Rotation {
+    /// As written in the document, such as `L68`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{}{}", direction, self.distance)
+    }
+}
+
+/// The safe's dial: positions numbered `0..size` and the one it points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    size: i64,
+    position: i64,
+}
+
+impl Default for Dial {
+    /// The puzzle's dial, 0 to 99 and pointing at 50
+    fn default() -> Dial {
+        Dial {
+            size: 100,
+            position: 50,
+        }
+    }
+}
+
+impl Dial {
+    /// A dial of `size` positions pointing at `start`
+    pub fn new(size: i64, start: i64) -> Result<Dial> {
+        if size < 1 {
+            return Err(Error::Usage(format!(
+                "Invalid dial size: {}. Use at least 1",
+                size
+            )));
+        }
+        if !(0..size).contains(&start) {
+            return Err(Error::Usage(format!(
+                "Invalid start: {}. Use a position between 0 and {}",
+                start,
+                size - 1
+            )));
+        }
+        Ok(Dial {
+            size,
+            position: start,
+        })
+    }
+
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+
+    /// Turn the dial, returning how many of the clicks landed on 0. Takes
+    /// the same time however long the rotation is.
+    pub fn rotate(&mut self, rotation: Rotation) -> i64 {
+        let (size, distance) = (i128::from(self.size), i128::from(rotation.distance));
+        let position = i128::from(self.position);
+
+        // Turning left from p is turning right from -p on a mirrored dial
+        let (from, end) = match rotation.direction {
+            Direction::Left => ((size - position) % size, position - distance),
+            Direction::Right => (position, position + distance),
+        };
+        self.position = end.rem_euclid(size) as i64;
+        ((from + distance) / size) as i64
+    }
+}
+
+/// The dial after one rotation of a trace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub rotation: Rotation,
+    /// Where the dial points afterwards
+    pub position: i64,
+    /// Clicks of the rotation that landed on 0
+    pub zeros: i64,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} -> {} ({} clicks on 0)",
+            self.rotation, self.position, self.zeros
+        )
+    }
+}
+
+/// Every rotation with where it leaves `dial` and how often it passes 0
+pub fn trace(rotations: &[Rotation], mut dial: Dial) -> Vec<Step> {
+    rotations
+        .iter()
+        .map(|&rotation| {
+            let zeros = dial.rotate(rotation);
+            Step {
+                rotation,
+                position: dial.position(),
+                zeros,
+            }
+        })
+        .collect()
+}
+
+/// Count rotations that leave the dial pointing at 0
+pub fn solve_safe_dial_part1(rotations: &[Rotation], dial: Dial) -> i64 {
+    trace(rotations, dial)
+        .iter()
+        .filter(|step| step.position == 0)
+        .count() as i64
+}
+
+/// Count every click that lands the dial on 0, including mid-rotation. Each
+/// rotation can pass 0 up to `i64::MAX` times, so the total is kept in 128 bits.
+pub fn solve_safe_dial_part2(rotations: &[Rotation], dial: Dial) -> i128 {
+    trace(rotations, dial)
+        .iter()
+        .map(|step| i128::from(step.zeros))
+        .sum()
+}
+
+/// Parse one rotation per line: `L` or `R` and a number of clicks
+pub fn parse_input(input: &str) -> Result<Vec<Rotation>> {
+    let mut rotations = Vec::new();
+
+    for line in input.lines() {
+        let rotation = line.trim();
+        let mut chars = rotation.chars();
+        let Some(direction) = chars.next() else {
+            continue;
+        };
+        let direction = match direction {
+            'L' => Direction::Left,
+            'R' => Direction::Right,
+            _ => {
+                return Err(Error::parse_at(
+                    input,
+                    rotation,
+                    format!("invalid direction '{}', expected 'L' or 'R'", direction),
+                ));
+            }
+        };
+
+        let distance = chars.as_str();
+        match distance.parse::<i64>() {
+            Ok(distance) if distance >= 0 => rotations.push(Rotation {
+                direction,
+                distance,
+            }),
+            _ => {
+                return Err(Error::parse_at(
+                    input,
+                    distance,
+                    format!("invalid distance '{}'", distance),
+                ));
+            }
+        }
+    }
+
+    Ok(rotations)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    const EXAMPLE: &'static str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    type Input = Vec<Rotation>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(rotations: &Self::Input) -> Result<Answer> {
+        Ok(solve_safe_dial_part1(rotations, Dial::default()).into())
+    }
+
+    fn part2(rotations: &Self::Input) -> Result<Answer> {
+        Ok(solve_safe_dial_part2(rotations, Dial::default()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part, Random};
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day1>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(3));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from(6));
+    }
+
+    /// The original click by click count, as an oracle
+    fn simulate_part2(rotations: &[Rotation], size: i64, start: i64) -> i128 {
+        let mut current_position = start;
+        let mut zero_count = 0;
+
+        for rotation in rotations {
+            let step = match rotation.direction {
+                Direction::Left => size - 1,
+                Direction::Right => 1,
+            };
+            for _ in 0..rotation.distance {
+                current_position = (current_position + step) % size;
+                if current_position == 0 {
+                    zero_count += 1;
+                }
+            }
+        }
+
+        zero_count
+    }
+
+    #[test]
+    fn closed_form_matches_simulation() {
+        let mut rng = Random::default();
+
+        for _ in 0..500 {
+            let size = 1 + rng.below(12) as i64;
+            let start = rng.below(size as u64) as i64;
+            let rotations: Vec<Rotation> = (0..rng.below(8))
+                .map(|_| Rotation {
+                    direction: if rng.below(2) == 0 {
+                        Direction::Left
+                    } else {
+                        Direction::Right
+                    },
+                    distance: rng.below(40) as i64,
+                })
+                .collect();
+
+            let dial = Dial::new(size, start).unwrap();
+            assert_eq!(
+                solve_safe_dial_part2(&rotations, dial),
+                simulate_part2(&rotations, size, start),
+                "{:?} on a dial of {} from {}",
+                rotations,
+                size,
+                start
+            );
+        }
+    }
+
+    #[test]
+    fn long_rotations_are_counted_at_once() {
+        let rotations = parse_input("R1000000000\nL9223372036854775807\n").unwrap();
+        let expected = 10_000_000 + (i64::MAX - 50) / 100 + 1;
+        assert_eq!(
+            solve_safe_dial_part2(&rotations, Dial::default()),
+            i128::from(expected)
+        );
+
+        let mut dial = Dial::new(100, 0).unwrap();
+        let left = |distance| Rotation {
+            direction: Direction::Left,
+            distance,
+        };
+        assert_eq!(dial.rotate(left(99)), 0);
+        assert_eq!(dial.rotate(left(99)), 1);
+    }
+
+    #[test]
+    fn totals_beyond_i64_do_not_overflow() {
+        let max = i128::from(i64::MAX);
+        let rotations = parse_input(&"R9223372036854775807\n".repeat(200)).unwrap();
+
+        // Turning only right passes 0 once per `size` clicks from the start
+        let tiny = Dial::new(1, 0).unwrap();
+        assert_eq!(solve_safe_dial_part2(&rotations, tiny), 200 * max);
+        assert_eq!(
+            solve_safe_dial_part2(&rotations, Dial::default()),
+            (50 + 200 * max) / 100
+        );
+    }
+
+    #[test]
+    fn traces_each_rotation() {
+        let rotations = parse_input(Day1::EXAMPLE).unwrap();
+        let steps = trace(&rotations, Dial::default());
+        assert_eq!(steps[0].to_string(), "L68 -> 82 (1 clicks on 0)");
+        assert_eq!(steps[2].to_string(), "R48 -> 0 (1 clicks on 0)");
+    }
+
+    #[test]
+    fn rejects_unknown_directions() {
+        let error = parse_input("L68\n X5\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            Error::parse(2, 2, "invalid direction 'X', expected 'L' or 'R'").to_string()
+        );
+        assert!(Dial::new(0, 0).is_err());
+        assert!(Dial::new(10, 10).is_err());
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day1>();
+    }
+}
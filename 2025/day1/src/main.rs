@@ -1,94 +1,49 @@
-use std::fs;
+use common::{Args, DayOption, Error, Format, Part, Result};
+use day1::{Day1, Dial, Rotation};
 
-fn solve_safe_dial_part1(input: &str) -> i32 {
-    let rotations: Vec<&str> = input.trim().lines().collect();
+const SIZE: DayOption = DayOption::value("--size", "positions");
+const START: DayOption = DayOption::value("--start", "position");
+const TRACE: DayOption = DayOption::flag("--trace");
 
-    let mut current_position = 50;
-    let mut zero_count = 0;
-
-    for rotation in rotations {
-        let direction = rotation.chars().next().unwrap();
-        let distance: i32 = rotation[1..].parse().unwrap();
-
-        if direction == 'L' {
-            current_position = (current_position - distance).rem_euclid(100);
-        } else if direction == 'R' {
-            current_position = (current_position + distance) % 100;
-        }
-
-        if current_position == 0 {
-            zero_count += 1;
-        }
+fn main() {
+    let args = Args::from_env_with::<Day1>(&[SIZE, START, TRACE]);
+    let dial = dial(&args).unwrap_or_else(|error| error.exit());
+    let trace = args.flag(TRACE.name);
+    if trace && args.format == Format::Json {
+        Error::Usage("--trace cannot be combined with --format json".to_string()).exit();
     }
 
-    zero_count
-}
-
-fn solve_safe_dial_part2(input: &str) -> i32 {
-    let rotations: Vec<&str> = input.trim().lines().collect();
-
-    let mut current_position = 50;
-    let mut zero_count = 0;
-
-    for rotation in rotations {
-        let direction = rotation.chars().next().unwrap();
-        let distance: i32 = rotation[1..].parse().unwrap();
-
-        if direction == 'L' {
-            // Moving left (decreasing): count each click that lands on 0
-            for _ in 0..distance {
-                current_position = if current_position == 0 {
-                    99
-                } else {
-                    current_position - 1
-                };
-                if current_position == 0 {
-                    zero_count += 1;
-                }
-            }
-        } else if direction == 'R' {
-            // Moving right (increasing): count each click that lands on 0
-            for _ in 0..distance {
-                current_position = if current_position == 99 {
-                    0
-                } else {
-                    current_position + 1
-                };
-                if current_position == 0 {
-                    zero_count += 1;
-                }
+    args.report_with::<Day1>(
+        |rotations| {
+            if trace {
+                print_trace(rotations, dial);
             }
-        }
-    }
-
-    zero_count
+            let password = match args.part {
+                Part::One => day1::solve_safe_dial_part1(rotations, dial).into(),
+                Part::Two => day1::solve_safe_dial_part2(rotations, dial).into(),
+            };
+            Ok(password)
+        },
+        |password| println!("Password: {}", password),
+    );
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-
-    if args.len() < 2 {
-        eprintln!("Usage: {} <part1|part2>", args[0]);
-        std::process::exit(1);
-    }
-
-    let part = &args[1];
-
-    match fs::read_to_string("input.txt") {
-        Ok(input) => {
-            let password = match part.as_str() {
-                "part1" => solve_safe_dial_part1(&input),
-                "part2" => solve_safe_dial_part2(&input),
-                _ => {
-                    eprintln!("Invalid part: {}. Use 'part1' or 'part2'", part);
-                    std::process::exit(1);
-                }
-            };
+/// The dial from `--size` and `--start`, the puzzle's by default. Without
+/// `--start` the dial points halfway round, as the puzzle's does.
+fn dial(args: &Args) -> Result<Dial> {
+    let size = args.value_as(SIZE.name)?.unwrap_or(Dial::default().size());
+    let start = args.value_as(START.name)?.unwrap_or(size / 2);
+    Dial::new(size, start)
+}
 
-            println!("Password: {}", password);
-        }
-        Err(error) => {
-            eprintln!("Error reading input.txt: {}", error);
-        }
+/// Print each rotation with where it leaves the dial and how often it passes 0
+fn print_trace(rotations: &[Rotation], dial: Dial) {
+    println!(
+        "Dial of {} positions starting at {}",
+        dial.size(),
+        dial.position()
+    );
+    for step in day1::trace(rotations, dial) {
+        println!("{}", step);
     }
 }
@@ -0,0 +1,253 @@
+//! Linear algebra over GF(2) on packed bits, for the indicator lights: each
+//! button toggles a set of lights, and pressing a button twice undoes it
+
+use std::fmt;
+
+/// A fixed length vector of bits packed into 64 bit words
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    /// All zeros
+    pub fn new(len: usize) -> BitVec {
+        BitVec {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// Ones at `indices`, which must be below `len`
+    pub fn from_indices(len: usize, indices: impl IntoIterator<Item = usize>) -> BitVec {
+        let mut bits = BitVec::new(len);
+        for i in indices {
+            bits.flip(i);
+        }
+        bits
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        if self.get(i) != value {
+            self.flip(i);
+        }
+    }
+
+    pub fn flip(&mut self, i: usize) {
+        self.words[i / 64] ^= 1 << (i % 64);
+    }
+
+    /// `self ^= other`, for vectors of the same length
+    pub fn xor_with(&mut self, other: &BitVec) {
+        for (word, &other) in self.words.iter_mut().zip(&other.words) {
+            *word ^= other;
+        }
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Indices of the set bits, in increasing order
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len).filter(|&i| self.get(i))
+    }
+}
+
+impl fmt::Debug for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..self.len {
+            write!(f, "{}", if self.get(i) { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+/// Every solution of `A x = b`: `particular` xor any combination of the
+/// null space basis vectors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solutions {
+    pub particular: BitVec,
+    pub null_space: Vec<BitVec>,
+}
+
+/// Most null space vectors [`Solutions::min_weight`] searches, as it tries
+/// `2^k` combinations of them
+pub const MAX_NULL_SPACE: usize = 30;
+
+impl Solutions {
+    /// A solution with the fewest ones, visiting all `2^k` combinations of
+    /// the `k` null space vectors in Gray code order so each step is a
+    /// single xor. `None` if `k` is over [`MAX_NULL_SPACE`], rather than
+    /// searching for ages.
+    pub fn min_weight(&self) -> Option<BitVec> {
+        let k = self.null_space.len();
+        if k > MAX_NULL_SPACE {
+            return None;
+        }
+
+        let mut current = self.particular.clone();
+        let mut best = current.clone();
+        let mut best_weight = best.count_ones();
+
+        for step in 1..1u64 << k {
+            current.xor_with(&self.null_space[step.trailing_zeros() as usize]);
+            let weight = current.count_ones();
+            if weight < best_weight {
+                best_weight = weight;
+                best.clone_from(&current);
+            }
+        }
+
+        Some(best)
+    }
+}
+
+/// Solve `A x = b` where column `j` of `A` is `columns[j]` (the rows it has
+/// a one in) and `b` is `target`. `None` if there is no solution.
+pub fn solve(columns: &[BitVec], target: &BitVec) -> Option<Solutions> {
+    let n = columns.len();
+    let m = target.len();
+
+    // Augmented rows [A | b]
+    let mut rows: Vec<BitVec> = (0..m)
+        .map(|r| {
+            let mut row = BitVec::new(n + 1);
+            for (c, column) in columns.iter().enumerate() {
+                row.set(c, column.get(r));
+            }
+            row.set(n, target.get(r));
+            row
+        })
+        .collect();
+
+    // Reduced row echelon form
+    let mut pivots = Vec::new();
+    for col in 0..n {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..m).find(|&r| rows[r].get(col)) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != rank && row.get(col) {
+                row.xor_with(&pivot_row);
+            }
+        }
+        pivots.push(col);
+    }
+
+    // A zero row with a one on the right means 0 = 1
+    if rows[pivots.len()..].iter().any(|row| row.get(n)) {
+        return None;
+    }
+
+    let mut particular = BitVec::new(n);
+    for (r, &col) in pivots.iter().enumerate() {
+        particular.set(col, rows[r].get(n));
+    }
+
+    let mut is_pivot = vec![false; n];
+    for &col in &pivots {
+        is_pivot[col] = true;
+    }
+    let null_space = (0..n)
+        .filter(|&free| !is_pivot[free])
+        .map(|free| {
+            let mut v = BitVec::new(n);
+            v.set(free, true);
+            for (r, &col) in pivots.iter().enumerate() {
+                v.set(col, rows[r].get(free));
+            }
+            v
+        })
+        .collect();
+
+    Some(Solutions {
+        particular,
+        null_space,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_across_words() {
+        let mut bits = BitVec::from_indices(130, [0, 63, 64, 129]);
+        assert_eq!(bits.count_ones(), 4);
+        assert!(bits.get(64) && !bits.get(65));
+        bits.xor_with(&BitVec::from_indices(130, [63, 65]));
+        assert_eq!(bits.ones().collect::<Vec<_>>(), vec![0, 64, 65, 129]);
+    }
+
+    #[test]
+    fn null_space_vectors_solve_the_homogeneous_system() {
+        let columns: Vec<BitVec> = [vec![0, 1], vec![1, 2], vec![0, 2], vec![0, 1, 2]]
+            .into_iter()
+            .map(|rows| BitVec::from_indices(3, rows))
+            .collect();
+        let target = BitVec::from_indices(3, [0, 2]);
+        let solutions = solve(&columns, &target).unwrap();
+
+        let apply = |x: &BitVec| {
+            let mut lights = BitVec::new(3);
+            for j in x.ones() {
+                lights.xor_with(&columns[j]);
+            }
+            lights
+        };
+        assert_eq!(apply(&solutions.particular), target);
+        for v in &solutions.null_space {
+            assert!(apply(v).is_zero());
+        }
+        assert_eq!(solutions.min_weight().unwrap().count_ones(), 1);
+    }
+
+    #[test]
+    fn detects_inconsistency() {
+        let columns = vec![BitVec::from_indices(2, [0, 1])];
+        assert_eq!(solve(&columns, &BitVec::from_indices(2, [0])), None);
+    }
+
+    #[test]
+    fn handles_more_than_64_buttons() {
+        // Light i is toggled by buttons i and i + 1; only the last button
+        // reaches the last light on its own
+        let n = 100;
+        let columns: Vec<BitVec> = (0..n)
+            .map(|j| BitVec::from_indices(n, [j, j + 1].into_iter().filter(|&i| i < n)))
+            .collect();
+        let target = BitVec::from_indices(n, [n - 1]);
+        let presses = solve(&columns, &target).unwrap().min_weight().unwrap();
+        assert_eq!(presses.ones().collect::<Vec<_>>(), vec![n - 1]);
+    }
+
+    #[test]
+    fn refuses_a_null_space_too_large_to_search() {
+        // Every button toggles the one light, so any two cancel out
+        let columns = vec![BitVec::from_indices(1, [0]); MAX_NULL_SPACE + 2];
+        let solutions = solve(&columns, &BitVec::from_indices(1, [0])).unwrap();
+        assert_eq!(solutions.null_space.len(), MAX_NULL_SPACE + 1);
+        assert_eq!(solutions.min_weight(), None);
+    }
+}
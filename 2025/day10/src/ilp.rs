@@ -0,0 +1,434 @@
+//! Exact integer programming for the joltage counters: branch and bound over
+//! a linear programming relaxation solved with rational simplex
+
+use crate::rational::{Checked, Overflow, Rational};
+use std::time::Instant;
+
+/// Outcome of the exact search for the fewest presses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Presses {
+    /// Proven minimum: how many times to press each button
+    Optimal(Vec<u64>),
+    /// Proven that no combination of presses reaches the targets
+    Infeasible,
+    /// The deadline passed first: the best presses found by then, if any,
+    /// which may not be the fewest
+    TimedOut(Option<Vec<u64>>),
+}
+
+impl Presses {
+    /// Total presses of an optimal solution
+    pub fn total(&self) -> Option<u64> {
+        match self {
+            Presses::Optimal(presses) => Some(presses.iter().sum()),
+            Presses::Infeasible | Presses::TimedOut(_) => None,
+        }
+    }
+}
+
+/// Minimize `sum(x)` subject to `a x = b` and `0 <= x <= upper`, `x` integer.
+/// `a` has one row per equation and one column per variable.
+pub fn minimize(a: &[Vec<i64>], b: &[i64], upper: &[i64]) -> Checked<Presses> {
+    minimize_until(a, b, upper, None)
+}
+
+/// [`minimize`], giving up at `deadline` with the best solution found so far
+pub fn minimize_until(
+    a: &[Vec<i64>],
+    b: &[i64],
+    upper: &[i64],
+    deadline: Option<Instant>,
+) -> Checked<Presses> {
+    let mut search = Search {
+        a,
+        b,
+        lower: vec![0; upper.len()],
+        upper: upper.to_vec(),
+        best: None,
+        deadline,
+        timed_out: false,
+    };
+    search.branch()?;
+
+    let best = search
+        .best
+        .map(|(_, x)| x.into_iter().map(|v| v.unsigned_abs()).collect());
+    match best {
+        _ if search.timed_out => Ok(Presses::TimedOut(best)),
+        Some(x) => Ok(Presses::Optimal(x)),
+        None => Ok(Presses::Infeasible),
+    }
+}
+
+/// Depth-first branch and bound, narrowing variable bounds at each branch
+struct Search<'a> {
+    a: &'a [Vec<i64>],
+    b: &'a [i64],
+    lower: Vec<i64>,
+    upper: Vec<i64>,
+    /// Best integer solution so far and its total
+    best: Option<(i128, Vec<i64>)>,
+    deadline: Option<Instant>,
+    /// Whether branches were abandoned because the deadline passed
+    timed_out: bool,
+}
+
+impl Search<'_> {
+    fn branch(&mut self) -> Checked<()> {
+        if self.timed_out
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            self.timed_out = true;
+            return Ok(());
+        }
+
+        let Some((value, x)) = relaxation(self.a, self.b, &self.lower, &self.upper)? else {
+            return Ok(());
+        };
+
+        // Totals are integers, so a relaxation rounding up to the best so far
+        // cannot lead to anything better
+        if let Some((best, _)) = &self.best
+            && value.ceil() >= *best
+        {
+            return Ok(());
+        }
+
+        let Some(j) = x.iter().position(|v| !v.is_integer()) else {
+            let x = x
+                .iter()
+                .map(|v| to_i64(v.floor()))
+                .collect::<Checked<_>>()?;
+            self.best = Some((value.floor(), x));
+            return Ok(());
+        };
+
+        let (lower, upper) = (self.lower[j], self.upper[j]);
+
+        self.upper[j] = to_i64(x[j].floor())?;
+        self.branch()?;
+        self.upper[j] = upper;
+
+        self.lower[j] = to_i64(x[j].ceil())?;
+        self.branch()?;
+        self.lower[j] = lower;
+        Ok(())
+    }
+}
+
+fn to_i64(n: i128) -> Checked<i64> {
+    i64::try_from(n).map_err(|_| Overflow)
+}
+
+/// Optimum of the relaxation `min sum(x)`, `a x = b`, `lower <= x <= upper`
+/// over the rationals, or `None` if it is infeasible
+fn relaxation(
+    a: &[Vec<i64>],
+    b: &[i64],
+    lower: &[i64],
+    upper: &[i64],
+) -> Checked<Option<(Rational, Vec<Rational>)>> {
+    let m = a.len();
+    let n = lower.len();
+    if (0..n).any(|j| lower[j] > upper[j]) {
+        return Ok(None);
+    }
+
+    // Shift to y = x - lower so every variable starts at zero, then columns
+    // are y (n), the slack of y <= upper - lower (n) and one artificial per
+    // equation (m), followed by the right-hand side
+    let width = 2 * n + m + 1;
+    let mut tableau = Tableau {
+        rows: Vec::with_capacity(m + n),
+        basis: Vec::with_capacity(m + n),
+    };
+
+    for i in 0..m {
+        let mut shifted = Rational::from(b[i]);
+        for j in 0..n {
+            let term = Rational::from(a[i][j]).checked_mul(Rational::from(lower[j]))?;
+            shifted = shifted.checked_sub(term)?;
+        }
+        // Keep the right-hand side non-negative so the artificials start feasible
+        let flip = shifted.is_negative();
+        let signed = |v: Rational| if flip { v.checked_neg() } else { Ok(v) };
+
+        let mut row = vec![Rational::ZERO; width];
+        for j in 0..n {
+            row[j] = signed(Rational::from(a[i][j]))?;
+        }
+        row[2 * n + i] = Rational::ONE;
+        row[width - 1] = signed(shifted)?;
+        tableau.rows.push(row);
+        tableau.basis.push(2 * n + i);
+    }
+
+    for j in 0..n {
+        let mut row = vec![Rational::ZERO; width];
+        row[j] = Rational::ONE;
+        row[n + j] = Rational::ONE;
+        row[width - 1] = Rational::from(upper[j]).checked_sub(Rational::from(lower[j]))?;
+        tableau.rows.push(row);
+        tableau.basis.push(n + j);
+    }
+
+    // Phase 1: drive the artificials to zero
+    let mut cost = vec![Rational::ZERO; width - 1];
+    for c in &mut cost[2 * n..] {
+        *c = Rational::ONE;
+    }
+    tableau.optimize(&cost, width - 1)?;
+    if tableau.value(&cost)?.is_positive() {
+        return Ok(None);
+    }
+    tableau.evict_artificials(2 * n)?;
+
+    // Phase 2: fewest presses, never letting an artificial back in
+    let mut cost = vec![Rational::ZERO; width - 1];
+    for c in &mut cost[..n] {
+        *c = Rational::ONE;
+    }
+    tableau.optimize(&cost, 2 * n)?;
+
+    let mut x: Vec<Rational> = lower.iter().map(|&l| Rational::from(l)).collect();
+    for (row, &var) in tableau.rows.iter().zip(&tableau.basis) {
+        if var < n {
+            x[var] = x[var].checked_add(row[width - 1])?;
+        }
+    }
+    let mut value = Rational::ZERO;
+    for &v in &x {
+        value = value.checked_add(v)?;
+    }
+    Ok(Some((value, x)))
+}
+
+/// Dense simplex tableau with one basic variable per row
+struct Tableau {
+    rows: Vec<Vec<Rational>>,
+    basis: Vec<usize>,
+}
+
+impl Tableau {
+    /// Current objective value for `cost`
+    fn value(&self, cost: &[Rational]) -> Checked<Rational> {
+        let mut value = Rational::ZERO;
+        for (row, &var) in self.rows.iter().zip(&self.basis) {
+            let rhs = row[row.len() - 1];
+            value = value.checked_add(cost[var].checked_mul(rhs)?)?;
+        }
+        Ok(value)
+    }
+
+    /// Minimize `cost` letting only columns before `entering` into the basis.
+    /// Bland's rule (lowest index first) guarantees termination.
+    fn optimize(&mut self, cost: &[Rational], entering: usize) -> Checked<()> {
+        loop {
+            let mut col = None;
+            for c in 0..entering {
+                if self.reduced_cost(cost, c)?.is_negative() {
+                    col = Some(c);
+                    break;
+                }
+            }
+            let Some(col) = col else {
+                return Ok(());
+            };
+
+            let rhs = self.rows[0].len() - 1;
+            let mut pivot: Option<(usize, Rational)> = None;
+            for (r, row) in self.rows.iter().enumerate() {
+                if !row[col].is_positive() {
+                    continue;
+                }
+                let ratio = row[rhs].checked_div(row[col])?;
+                let better = match pivot {
+                    None => true,
+                    Some((p, best)) => {
+                        ratio < best || (ratio == best && self.basis[r] < self.basis[p])
+                    }
+                };
+                if better {
+                    pivot = Some((r, ratio));
+                }
+            }
+
+            // Every variable is bounded, so the relaxation never is unbounded
+            let Some((row, _)) = pivot else {
+                return Ok(());
+            };
+            self.pivot(row, col)?;
+        }
+    }
+
+    fn reduced_cost(&self, cost: &[Rational], col: usize) -> Checked<Rational> {
+        if self.basis.contains(&col) {
+            return Ok(Rational::ZERO);
+        }
+        let mut reduced = cost[col];
+        for (row, &var) in self.rows.iter().zip(&self.basis) {
+            reduced = reduced.checked_sub(cost[var].checked_mul(row[col])?)?;
+        }
+        Ok(reduced)
+    }
+
+    /// Swap artificials still in the basis (at zero) for real variables where
+    /// the row allows it; rows where it does not are redundant equations
+    fn evict_artificials(&mut self, first_artificial: usize) -> Checked<()> {
+        for r in 0..self.rows.len() {
+            if self.basis[r] < first_artificial {
+                continue;
+            }
+            if let Some(col) = (0..first_artificial).find(|&col| !self.rows[r][col].is_zero()) {
+                self.pivot(r, col)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn pivot(&mut self, row: usize, col: usize) -> Checked<()> {
+        let factor = self.rows[row][col];
+        for v in &mut self.rows[row] {
+            *v = v.checked_div(factor)?;
+        }
+
+        let pivot_row = self.rows[row].clone();
+        for (r, other) in self.rows.iter_mut().enumerate() {
+            let factor = other[col];
+            if r == row || factor.is_zero() {
+                continue;
+            }
+            for (v, &p) in other.iter_mut().zip(&pivot_row) {
+                *v = v.checked_sub(factor.checked_mul(p)?)?;
+            }
+        }
+        self.basis[row] = col;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Random;
+
+    #[test]
+    fn needs_integer_presses_not_the_fractional_relaxation() {
+        // x0 + x1 = 3, x1 + x2 = 3, x0 + x2 = 3 has the relaxed solution
+        // 1.5 each, but no integer solution
+        let a = vec![vec![1, 1, 0], vec![0, 1, 1], vec![1, 0, 1]];
+        assert_eq!(
+            minimize(&a, &[3, 3, 3], &[3, 3, 3]).unwrap(),
+            Presses::Infeasible
+        );
+
+        // An extra button covering every counter, pressed 3 times, is the
+        // integer optimum
+        let a = vec![vec![1, 1, 0, 1], vec![0, 1, 1, 1], vec![1, 0, 1, 1]];
+        let presses = minimize(&a, &[3, 3, 3], &[3, 3, 3, 3]).unwrap();
+        assert_eq!(presses.total(), Some(3));
+    }
+
+    #[test]
+    fn proves_infeasibility() {
+        // One button adds to both counters, which need different levels
+        let a = vec![vec![1], vec![1]];
+        assert_eq!(minimize(&a, &[1, 2], &[1]).unwrap(), Presses::Infeasible);
+    }
+
+    #[test]
+    fn solution_satisfies_constraints() {
+        let a = vec![
+            vec![0, 0, 0, 0, 1, 1],
+            vec![0, 1, 0, 0, 0, 1],
+            vec![0, 0, 1, 1, 1, 0],
+            vec![1, 1, 0, 1, 0, 0],
+        ];
+        let b = [3, 5, 4, 7];
+        let Ok(Presses::Optimal(x)) = minimize(&a, &b, &[7, 5, 4, 4, 3, 3]) else {
+            panic!("expected a solution");
+        };
+        assert_eq!(x.iter().sum::<u64>(), 10);
+        for (row, &target) in a.iter().zip(&b) {
+            let level: i64 = row.iter().zip(&x).map(|(&c, &p)| c * p as i64).sum();
+            assert_eq!(level, target);
+        }
+    }
+
+    /// Smallest total over every combination of presses within `upper`
+    fn brute_force(a: &[Vec<i64>], b: &[i64], upper: &[i64]) -> Option<u64> {
+        let mut x = vec![0i64; upper.len()];
+        let mut best = None;
+        loop {
+            let fits = a.iter().zip(b).all(|(row, &target)| {
+                row.iter().zip(&x).map(|(c, v)| c * v).sum::<i64>() == target
+            });
+            if fits {
+                let total = x.iter().sum::<i64>() as u64;
+                best = Some(best.map_or(total, |best: u64| best.min(total)));
+            }
+
+            // Next combination, like an odometer
+            let Some(j) = (0..x.len()).find(|&j| x[j] < upper[j]) else {
+                return best;
+            };
+            x[j] += 1;
+            x[..j].fill(0);
+        }
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_systems() {
+        let mut rng = Random::default();
+
+        for _ in 0..200 {
+            let m = 1 + rng.below(4) as usize;
+            let n = 1 + rng.below(5) as usize;
+            let a: Vec<Vec<i64>> = (0..m)
+                .map(|_| (0..n).map(|_| rng.below(2) as i64).collect())
+                .collect();
+            let b: Vec<i64> = (0..m).map(|_| rng.below(7) as i64).collect();
+            let upper = vec![6; n];
+
+            let presses = minimize(&a, &b, &upper).unwrap();
+            assert_eq!(
+                presses.total(),
+                brute_force(&a, &b, &upper),
+                "{:?} = {:?}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        // Coefficients this large make the simplex fractions outgrow i128
+        let big = i64::MAX;
+        let a = vec![
+            vec![big, big - 1, 1],
+            vec![big - 2, 1, big - 3],
+            vec![1, big - 4, big - 5],
+        ];
+        assert_eq!(
+            minimize(&a, &[big, big, big], &[big, big, big]),
+            Err(Overflow)
+        );
+    }
+
+    #[test]
+    fn stops_at_the_deadline() {
+        let a = vec![vec![2, 3]];
+        let expired = Some(Instant::now());
+        assert_eq!(
+            minimize_until(&a, &[7], &[3, 3], expired),
+            Ok(Presses::TimedOut(None))
+        );
+        assert_eq!(
+            minimize_until(&a, &[7], &[3, 3], None),
+            Ok(Presses::Optimal(vec![2, 1]))
+        );
+    }
+}
@@ -0,0 +1,519 @@
+//! Day 10: Factory
+
+pub mod gf2;
+pub mod ilp;
+pub mod manual;
+pub mod rational;
+
+pub use gf2::BitVec;
+pub use ilp::Presses;
+pub use rational::{Checked, Overflow, Rational};
+
+use common::{Answer, Error, Result, Solution};
+use rayon::prelude::*;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A factory machine as described by one line of the manual
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    /// Desired indicator light pattern (`#` is on)
+    pub target: Vec<bool>,
+    /// Lights / counters toggled or incremented by each button
+    pub buttons: Vec<Vec<usize>>,
+    /// Required joltage level of each counter
+    pub joltage: Vec<i64>,
+}
+
+/// Parse one machine per line: `[lights] (buttons)... {joltage}`
+pub fn parse_input(input: &str) -> Result<Vec<Machine>> {
+    manual::parse(input)
+}
+
+/// Buttons to press (each once) to reach the target light pattern with the
+/// fewest presses, or `None` if no presses reach it. Fails when too many
+/// buttons are redundant to try every combination of them.
+pub fn solve_machine(machine: &Machine) -> Result<Option<Vec<usize>>> {
+    let n_lights = machine.target.len();
+    let columns: Vec<BitVec> = machine
+        .buttons
+        .iter()
+        .map(|button| BitVec::from_indices(n_lights, button.iter().copied()))
+        .collect();
+    let target = BitVec::from_indices(
+        n_lights,
+        (0..n_lights).filter(|&light| machine.target[light]),
+    );
+
+    let Some(solutions) = gf2::solve(&columns, &target) else {
+        return Ok(None);
+    };
+    match solutions.min_weight() {
+        Some(presses) => Ok(Some(presses.ones().collect())),
+        None => Err(Error::overflow(format!(
+            "{} redundant buttons, more than the {} whose combinations can be searched",
+            solutions.null_space.len(),
+            gf2::MAX_NULL_SPACE
+        ))),
+    }
+}
+
+/// Fewest button presses to reach the joltage requirements, proven optimal,
+/// or proof that no presses reach them. Fails rather than guess if the exact
+/// arithmetic overflows.
+pub fn solve_machine_part2(machine: &Machine) -> Checked<Presses> {
+    solve_machine_part2_within(machine, None)
+}
+
+/// [`solve_machine_part2`], settling for the best presses found if the
+/// search takes longer than `budget`
+pub fn solve_machine_part2_within(machine: &Machine, budget: Option<Duration>) -> Checked<Presses> {
+    let deadline = budget.and_then(|budget| Instant::now().checked_add(budget));
+    if machine.joltage.iter().any(|&level| level < 0) {
+        return Ok(Presses::Infeasible);
+    }
+
+    let n_counters = machine.joltage.len();
+    let n_buttons = machine.buttons.len();
+
+    // A[i][j] = 1 if button j affects counter i
+    let mut matrix = vec![vec![0i64; n_buttons]; n_counters];
+    for (col, button) in machine.buttons.iter().enumerate() {
+        for &counter in button {
+            if counter < n_counters {
+                matrix[counter][col] = 1;
+            }
+        }
+    }
+
+    // Counters only go up, so no button can be pressed more often than the
+    // lowest requirement among the counters it affects
+    let upper: Vec<i64> = machine
+        .buttons
+        .iter()
+        .map(|button| {
+            button
+                .iter()
+                .filter_map(|&counter| machine.joltage.get(counter))
+                .min()
+                .copied()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    ilp::minimize_until(&matrix, &machine.joltage, &upper, deadline)
+}
+
+/// Lights on after pressing each of `buttons` once, starting all off
+pub fn press_lights(machine: &Machine, buttons: &[usize]) -> Vec<bool> {
+    let mut lights = vec![false; machine.target.len()];
+    for &button in buttons {
+        for &light in &machine.buttons[button] {
+            if let Some(light) = lights.get_mut(light) {
+                *light = !*light;
+            }
+        }
+    }
+    lights
+}
+
+/// Counter levels after pressing button `j` `presses[j]` times, starting at 0
+pub fn press_counters(machine: &Machine, presses: &[u64]) -> Vec<i64> {
+    let mut counters = vec![0i64; machine.joltage.len()];
+    for (button, &times) in machine.buttons.iter().zip(presses) {
+        for &counter in button {
+            if let Some(level) = counters.get_mut(counter) {
+                *level = level.saturating_add_unsigned(times);
+            }
+        }
+    }
+    counters
+}
+
+/// What a machine shows after its buttons are pressed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum State {
+    Lights(Vec<bool>),
+    Counters(Vec<i64>),
+}
+
+impl fmt::Display for State {
+    /// In the manual's notation: `[.##.]` or `{3,5,4,7}`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            State::Lights(lights) => manual::write_lights(f, lights),
+            State::Counters(levels) => manual::write_list(f, '{', levels, '}'),
+        }
+    }
+}
+
+/// A machine's solution replayed on a simulated machine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// Buttons pressed (by index) and how many times, skipping unpressed ones
+    pub presses: Vec<(usize, u64)>,
+    /// State reached by pressing them
+    pub reached: State,
+    /// State the machine asks for
+    pub expected: State,
+}
+
+impl Explanation {
+    pub fn total(&self) -> u64 {
+        self.presses.iter().map(|&(_, times)| times).sum()
+    }
+
+    /// Whether the simulation confirms the solver
+    pub fn is_verified(&self) -> bool {
+        self.reached == self.expected
+    }
+}
+
+impl fmt::Display for Explanation {
+    /// `button 1 x3, button 4 x1 = 4 presses -> {3,5,4,7} ok`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (button, times)) in self.presses.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{}button {} x{}", separator, button, times)?;
+        }
+        if self.presses.is_empty() {
+            write!(f, "nothing")?;
+        }
+        write!(f, " = {} presses -> {}", self.total(), self.reached)?;
+        if self.is_verified() {
+            write!(f, " ok")
+        } else {
+            write!(f, " MISMATCH, expected {}", self.expected)
+        }
+    }
+}
+
+/// Solve one machine's lights and check the buttons on a simulated machine.
+/// `None` if no presses reach the target.
+pub fn explain_part1(machine: &Machine) -> Result<Option<Explanation>> {
+    Ok(solve_machine(machine)?.map(|buttons| Explanation {
+        presses: buttons.iter().map(|&button| (button, 1)).collect(),
+        reached: State::Lights(press_lights(machine, &buttons)),
+        expected: State::Lights(machine.target.clone()),
+    }))
+}
+
+/// Check the part 2 `presses` found for a machine on a simulated machine.
+/// `None` if no presses were found.
+pub fn explain_part2(machine: &Machine, presses: &Presses) -> Option<Explanation> {
+    let (Presses::Optimal(presses) | Presses::TimedOut(Some(presses))) = presses else {
+        return None;
+    };
+    Some(Explanation {
+        presses: (0..presses.len())
+            .filter(|&button| presses[button] > 0)
+            .map(|button| (button, presses[button]))
+            .collect(),
+        reached: State::Counters(press_counters(machine, presses)),
+        expected: State::Counters(machine.joltage.clone()),
+    })
+}
+
+/// Sum of the fewest presses over all machines for the indicator lights
+pub fn solve_part1(machines: &[Machine]) -> Result<usize> {
+    let mut total_part1 = 0;
+    let mut unsolvable = Vec::new();
+
+    for (i, machine) in machines.iter().enumerate() {
+        match solve_machine(machine) {
+            Ok(Some(buttons)) => total_part1 += buttons.len(),
+            Ok(None) => unsolvable.push(i + 1),
+            Err(Error::Overflow(message)) => {
+                return Err(Error::overflow(format!(
+                    "machine {} has {}",
+                    i + 1,
+                    message
+                )));
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    if !unsolvable.is_empty() {
+        return Err(unsolvable_machines(&unsolvable));
+    }
+    Ok(total_part1)
+}
+
+/// Limits and feedback for a long part 2 run
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Part2Options {
+    /// Time allowed per machine before settling for the best presses found
+    pub budget: Option<Duration>,
+    /// Keep a line on stderr with the machines done and the slowest so far
+    pub progress: bool,
+}
+
+/// Sum of the fewest presses over all machines for the joltage counters
+pub fn solve_part2(machines: &[Machine]) -> Result<u64> {
+    solve_part2_with(machines, Part2Options::default())
+}
+
+/// [`solve_part2`] with a time budget per machine. Machines that hit it
+/// count with the best presses found in time and are listed in a warning;
+/// if one had found none, the total is unknown and that is an error.
+pub fn solve_part2_with(machines: &[Machine], options: Part2Options) -> Result<u64> {
+    sum_part2(&solve_machines_part2(machines, options), options.budget)
+}
+
+/// Each machine's part 2 presses, solved in parallel within `options`
+pub fn solve_machines_part2(machines: &[Machine], options: Part2Options) -> Vec<Checked<Presses>> {
+    let progress = Progress::new(machines.len(), options.progress);
+
+    // Machines vary wildly in difficulty, so let idle threads steal work
+    // rather than giving each thread a fixed share
+    let results = machines
+        .par_iter()
+        .enumerate()
+        .map(|(i, machine)| {
+            let start = Instant::now();
+            let presses = solve_machine_part2_within(machine, options.budget);
+            progress.done(i + 1, start.elapsed());
+            presses
+        })
+        .collect();
+    progress.finish();
+    results
+}
+
+/// Total of [`solve_machines_part2`]'s `results`, found within `budget`
+pub fn sum_part2(results: &[Checked<Presses>], budget: Option<Duration>) -> Result<u64> {
+    let mut total_part2 = 0;
+    let mut unsolvable = Vec::new();
+    let mut timed_out = Vec::new();
+    let mut none_in_time = Vec::new();
+
+    for (i, presses) in results.iter().enumerate() {
+        match presses {
+            Ok(Presses::Optimal(presses)) => total_part2 += presses.iter().sum::<u64>(),
+            Ok(Presses::Infeasible) => unsolvable.push(i + 1),
+            Ok(Presses::TimedOut(Some(presses))) => {
+                total_part2 += presses.iter().sum::<u64>();
+                timed_out.push(i + 1);
+            }
+            Ok(Presses::TimedOut(None)) => {
+                timed_out.push(i + 1);
+                none_in_time.push(i + 1);
+            }
+            Err(Overflow) => {
+                return Err(Error::overflow(format!(
+                    "machine {} needs numbers beyond 128 bits",
+                    i + 1
+                )));
+            }
+        }
+    }
+
+    if !unsolvable.is_empty() {
+        return Err(unsolvable_machines(&unsolvable));
+    }
+    if let Some(budget) = budget
+        && !timed_out.is_empty()
+    {
+        let summary = format!(
+            "{} machines hit the {:?} budget: {:?}",
+            timed_out.len(),
+            budget,
+            timed_out
+        );
+        if !none_in_time.is_empty() {
+            return Err(Error::unsolvable(format!(
+                "{}, and {:?} found no presses in time",
+                summary, none_in_time
+            )));
+        }
+        common::warn(format!("{}, so the total may not be the fewest", summary));
+    }
+    Ok(total_part2)
+}
+
+/// Live `done / total` line on stderr, updated as machines finish
+struct Progress {
+    total: usize,
+    enabled: bool,
+    /// Machines done, and the slowest one (1-based) with its time
+    state: Mutex<(usize, Option<(usize, Duration)>)>,
+}
+
+impl Progress {
+    fn new(total: usize, enabled: bool) -> Progress {
+        Progress {
+            total,
+            enabled,
+            state: Mutex::new((0, None)),
+        }
+    }
+
+    fn done(&self, machine: usize, elapsed: Duration) {
+        if !self.enabled {
+            return;
+        }
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let (done, slowest) = &mut *state;
+        *done += 1;
+        if slowest.is_none_or(|(_, time)| elapsed > time) {
+            *slowest = Some((machine, elapsed));
+        }
+        if let Some((machine, time)) = slowest {
+            // Clear the rest of the line in case the previous one was longer
+            eprint!(
+                "\r\x1b[KMachines {}/{}, slowest so far: machine {} ({:.2?})",
+                done, self.total, machine, time
+            );
+        }
+    }
+
+    fn finish(&self) {
+        if self.enabled {
+            eprintln!();
+        }
+    }
+}
+
+/// Report machines (1-based) that no button presses can satisfy
+fn unsolvable_machines(machines: &[usize]) -> Error {
+    Error::unsolvable(format!(
+        "{} machines cannot reach their targets: {:?}",
+        machines.len(),
+        machines
+    ))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    const EXAMPLE: &'static str = "\
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
+";
+
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(machines: &Self::Input) -> Result<Answer> {
+        Ok(solve_part1(machines)?.into())
+    }
+
+    fn part2(machines: &Self::Input) -> Result<Answer> {
+        Ok(solve_part2(machines)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part};
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day10>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(7));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from(33));
+    }
+
+    #[test]
+    fn pressed_buttons_reach_the_target() {
+        let machines = parse_input(Day10::EXAMPLE).unwrap();
+        let presses: Vec<usize> = machines
+            .iter()
+            .map(|machine| {
+                let buttons = solve_machine(machine).unwrap().unwrap();
+                let mut lights = vec![false; machine.target.len()];
+                for &button in &buttons {
+                    for &light in &machine.buttons[button] {
+                        lights[light] = !lights[light];
+                    }
+                }
+                assert_eq!(lights, machine.target);
+                buttons.len()
+            })
+            .collect();
+        assert_eq!(presses, vec![2, 3, 2]);
+    }
+
+    #[test]
+    fn explanations_replay_the_solution() {
+        let machine = &parse_input(Day10::EXAMPLE).unwrap()[0];
+        let lights = explain_part1(machine).unwrap().unwrap();
+        assert!(lights.is_verified());
+        assert_eq!(
+            lights.to_string(),
+            "button 1 x1, button 3 x1 = 2 presses -> [.##.] ok"
+        );
+
+        let presses = solve_machine_part2(machine).unwrap();
+        let counters = explain_part2(machine, &presses).unwrap();
+        assert!(counters.is_verified());
+        assert_eq!(counters.total(), 10);
+        assert!(
+            counters
+                .to_string()
+                .ends_with("= 10 presses -> {3,5,4,7} ok")
+        );
+    }
+
+    #[test]
+    fn explanation_flags_a_wrong_state() {
+        let machine = &parse_input(Day10::EXAMPLE).unwrap()[0];
+        let wrong = Explanation {
+            presses: vec![(0, 1)],
+            reached: State::Lights(press_lights(machine, &[0])),
+            expected: State::Lights(machine.target.clone()),
+        };
+        assert!(!wrong.is_verified());
+        assert_eq!(
+            wrong.to_string(),
+            "button 0 x1 = 1 presses -> [...#] MISMATCH, expected [.##.]"
+        );
+    }
+
+    #[test]
+    fn machines_over_budget_are_listed() {
+        let machines = parse_input(Day10::EXAMPLE).unwrap();
+        let options = Part2Options {
+            budget: Some(Duration::from_nanos(1)),
+            progress: false,
+        };
+        let error = solve_part2_with(&machines, options).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("3 machines hit the 1ns budget: [1, 2, 3]")
+        );
+
+        let options = Part2Options {
+            budget: Some(Duration::from_secs(60)),
+            progress: false,
+        };
+        assert_eq!(solve_part2_with(&machines, options).unwrap(), 33);
+        assert!(common::take_warnings().is_empty());
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day10>();
+    }
+}
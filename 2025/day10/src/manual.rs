@@ -0,0 +1,331 @@
+//! The manual's notation for a machine, one per line:
+//! `[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}`
+
+use crate::Machine;
+use common::{Error, Result};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// One of `[ ] ( ) { } ,`
+    Symbol(char),
+    /// A run of digits, possibly negative
+    Number,
+    /// A run of `.` and `#`
+    Lights,
+}
+
+/// A token with its text, a slice of the input so errors can point at it
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: Kind,
+    text: &'a str,
+}
+
+/// Split a line into tokens, skipping whitespace
+fn tokenize<'a>(input: &str, line: &'a str) -> Result<Vec<Token<'a>>> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let kind = match c {
+            _ if c.is_whitespace() => continue,
+            '[' | ']' | '(' | ')' | '{' | '}' | ',' => Kind::Symbol(c),
+            '.' | '#' => Kind::Lights,
+            '-' | '0'..='9' => Kind::Number,
+            _ => {
+                let text = &line[start..start + c.len_utf8()];
+                return Err(Error::parse_at(
+                    input,
+                    text,
+                    format!("unexpected character '{}'", c),
+                ));
+            }
+        };
+
+        let continues = |c: char| match kind {
+            Kind::Lights => c == '.' || c == '#',
+            Kind::Number => c.is_ascii_digit(),
+            Kind::Symbol(_) => false,
+        };
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, c)) = chars.peek()
+            && continues(c)
+        {
+            end = i + c.len_utf8();
+            chars.next();
+        }
+
+        tokens.push(Token {
+            kind,
+            text: &line[start..end],
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent over one line's tokens
+struct Parser<'a> {
+    input: &'a str,
+    line: &'a str,
+    tokens: Vec<Token<'a>>,
+    next: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.next).copied()
+    }
+
+    fn bump(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.next += 1;
+        token
+    }
+
+    fn peek_symbol(&self, symbol: char) -> bool {
+        self.peek()
+            .is_some_and(|token| token.kind == Kind::Symbol(symbol))
+    }
+
+    /// An error pointing at `token`, or at the end of the line without one
+    fn error(&self, token: Option<Token<'a>>, message: impl Into<String>) -> Error {
+        let text = token.map_or(&self.line[self.line.len()..], |token| token.text);
+        Error::parse_at(self.input, text, message)
+    }
+
+    /// An error for an unexpected `token` where `expected` should be
+    fn unexpected(&self, token: Option<Token<'a>>, expected: &str) -> Error {
+        let found = match token {
+            Some(token) => format!("'{}'", token.text),
+            None => "end of line".to_string(),
+        };
+        self.error(token, format!("expected {}, found {}", expected, found))
+    }
+
+    fn expect(&mut self, symbol: char, expected: &str) -> Result<Token<'a>> {
+        match self.bump() {
+            Some(token) if token.kind == Kind::Symbol(symbol) => Ok(token),
+            token => Err(self.unexpected(token, expected)),
+        }
+    }
+
+    /// `n (, n)* close`, after the opening symbol
+    fn numbers(&mut self, close: char) -> Result<Vec<Token<'a>>> {
+        let mut numbers = Vec::new();
+        loop {
+            match self.bump() {
+                Some(token) if token.kind == Kind::Number => numbers.push(token),
+                token => return Err(self.unexpected(token, "a number")),
+            }
+            match self.bump() {
+                Some(token) if token.kind == Kind::Symbol(',') => {}
+                Some(token) if token.kind == Kind::Symbol(close) => return Ok(numbers),
+                token => return Err(self.unexpected(token, &format!("',' or '{}'", close))),
+            }
+        }
+    }
+
+    /// `[lights] (button)... {joltage}`
+    fn machine(&mut self) -> Result<Machine> {
+        self.expect('[', "'[' starting the light diagram")?;
+        let target: Vec<bool> = match self.peek() {
+            Some(token) if token.kind == Kind::Lights => {
+                self.bump();
+                token.text.chars().map(|c| c == '#').collect()
+            }
+            _ => Vec::new(),
+        };
+        self.expect(']', "'.', '#' or ']'")?;
+
+        let mut buttons = Vec::new();
+        let mut wiring = Vec::new();
+        while self.peek_symbol('(') {
+            self.bump();
+            let indices = self.numbers(')')?;
+            let mut button = Vec::new();
+            for token in &indices {
+                let light = self.index(*token, &button, target.len(), "light")?;
+                button.push(light);
+            }
+            buttons.push(button);
+            wiring.extend(indices);
+        }
+
+        self.expect('{', "'(' or '{'")?;
+        let mut joltage = Vec::new();
+        for token in self.numbers('}')? {
+            match token.text.parse::<i64>() {
+                Ok(level) if level >= 0 => joltage.push(level),
+                _ => {
+                    return Err(
+                        self.error(Some(token), format!("invalid joltage '{}'", token.text))
+                    );
+                }
+            }
+        }
+
+        // Buttons also drive the counters, so they must exist too
+        for token in wiring {
+            self.index(token, &[], joltage.len(), "counter")?;
+        }
+
+        if let Some(token) = self.bump() {
+            return Err(self.error(
+                Some(token),
+                format!("unexpected '{}' after the joltage", token.text),
+            ));
+        }
+
+        Ok(Machine {
+            target,
+            buttons,
+            joltage,
+        })
+    }
+
+    /// A button's index of one of `count` lights or counters, not already
+    /// in `seen`
+    fn index(&self, token: Token<'a>, seen: &[usize], count: usize, what: &str) -> Result<usize> {
+        let Ok(index) = token.text.parse::<usize>() else {
+            return Err(self.error(Some(token), format!("invalid {} '{}'", what, token.text)));
+        };
+        if index >= count {
+            return Err(self.error(
+                Some(token),
+                format!("{} {} out of range for {} {}s", what, index, count, what),
+            ));
+        }
+        if seen.contains(&index) {
+            return Err(self.error(
+                Some(token),
+                format!("{} {} wired twice to the same button", what, index),
+            ));
+        }
+        Ok(index)
+    }
+}
+
+/// Parse one machine per line, skipping blank lines
+pub fn parse(input: &str) -> Result<Vec<Machine>> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut parser = Parser {
+                input,
+                line,
+                tokens: tokenize(input, line)?,
+                next: 0,
+            };
+            parser.machine()
+        })
+        .collect()
+}
+
+/// `[.##.]`
+pub(crate) fn write_lights(f: &mut fmt::Formatter<'_>, lights: &[bool]) -> fmt::Result {
+    write!(f, "[")?;
+    for &on in lights {
+        write!(f, "{}", if on { '#' } else { '.' })?;
+    }
+    write!(f, "]")
+}
+
+/// `open` followed by comma separated `values` and `close`, as in `{3,5,4,7}`
+pub(crate) fn write_list<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    open: char,
+    values: &[T],
+    close: char,
+) -> fmt::Result {
+    write!(f, "{}", open)?;
+    for (i, value) in values.iter().enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        write!(f, "{}{}", separator, value)?;
+    }
+    write!(f, "{}", close)
+}
+
+impl fmt::Display for Machine {
+    /// The manual's line for this machine, which [`parse`] reads back
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_lights(f, &self.target)?;
+        for button in &self.buttons {
+            write!(f, " ")?;
+            write_list(f, '(', button, ')')?;
+        }
+        write!(f, " ")?;
+        write_list(f, '{', &self.joltage, '}')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+    use common::Solution;
+
+    fn error(input: &str) -> (usize, usize, String) {
+        match parse(input) {
+            Err(Error::Parse {
+                line,
+                column,
+                message,
+            }) => (line, column, message),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn round_trips_the_example() {
+        let machines = parse(Day10::EXAMPLE).unwrap();
+        let text: String = machines.iter().map(|m| format!("{}\n", m)).collect();
+        assert_eq!(text, Day10::EXAMPLE);
+        assert_eq!(parse(&text).unwrap(), machines);
+    }
+
+    #[test]
+    fn tolerates_spacing() {
+        let machine = &parse("  [ .# ]( 1 , 0 )(1){ 2,3 }  \n\n").unwrap()[0];
+        assert_eq!(machine.to_string(), "[.#] (1,0) (1) {2,3}");
+    }
+
+    #[test]
+    fn points_at_the_offending_token() {
+        let valid = "[.#] (0) {1,1}\n";
+        assert_eq!(
+            error(&format!("{}[.#] (0,2) {{1,1}}", valid)),
+            (2, 9, "light 2 out of range for 2 lights".to_string())
+        );
+        assert_eq!(
+            error("[.##] (0) (2) {1,1}"),
+            (1, 12, "counter 2 out of range for 2 counters".to_string())
+        );
+        assert_eq!(
+            error("[.#] (0,x) {1,1}"),
+            (1, 9, "unexpected character 'x'".to_string())
+        );
+        assert_eq!(
+            error("[.#] (0 1) {1,1}"),
+            (1, 9, "expected ',' or ')', found '1'".to_string())
+        );
+        assert_eq!(
+            error("[.#] (1,1) {1,1}"),
+            (1, 9, "light 1 wired twice to the same button".to_string())
+        );
+        assert_eq!(
+            error("[.#] (0) {1,-1}"),
+            (1, 13, "invalid joltage '-1'".to_string())
+        );
+        assert_eq!(
+            error("[.#] (0) {1,1} (1)"),
+            (1, 16, "unexpected '(' after the joltage".to_string())
+        );
+        assert_eq!(
+            error("[.#] (0)"),
+            (1, 9, "expected '(' or '{', found end of line".to_string())
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! Exact fractions for the linear programming relaxation. Every operation is
+//! checked: a result that does not fit is reported as [`Overflow`] rather
+//! than wrapping around into a wrong answer.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+/// An arithmetic result cannot be represented: it does not fit in the
+/// fraction's integers, or divides by zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "arithmetic overflow")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Result of checked arithmetic
+pub type Checked<T> = std::result::Result<T, Overflow>;
+
+/// A fraction kept in lowest terms with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+fn gcd(mut a: i128, mut b: i128) -> Checked<i128> {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.checked_abs().ok_or(Overflow)
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    /// `num / den` in lowest terms; fails on a zero denominator or when the
+    /// sign cannot be moved to the numerator
+    pub fn new(num: i128, den: i128) -> Checked<Rational> {
+        if den == 0 {
+            return Err(Overflow);
+        }
+        let g = gcd(num, den)?.max(1);
+        let (num, den) = (num / g, den / g);
+        if den < 0 {
+            Ok(Rational {
+                num: num.checked_neg().ok_or(Overflow)?,
+                den: den.checked_neg().ok_or(Overflow)?,
+            })
+        } else {
+            Ok(Rational { num, den })
+        }
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.num
+    }
+
+    pub fn denominator(self) -> i128 {
+        self.den
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.den == 1
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    pub fn is_positive(self) -> bool {
+        self.num > 0
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.num < 0
+    }
+
+    /// Largest integer not above this value
+    pub fn floor(self) -> i128 {
+        self.num.div_euclid(self.den)
+    }
+
+    /// Smallest integer not below this value
+    pub fn ceil(self) -> i128 {
+        let floor = self.floor();
+        if self.is_integer() { floor } else { floor + 1 }
+    }
+
+    pub fn checked_neg(self) -> Checked<Rational> {
+        Ok(Rational {
+            num: self.num.checked_neg().ok_or(Overflow)?,
+            den: self.den,
+        })
+    }
+
+    /// Sum over the least common denominator, so intermediate values only
+    /// overflow when the result itself nearly does
+    pub fn checked_add(self, other: Rational) -> Checked<Rational> {
+        let g = gcd(self.den, other.den)?;
+        let (a, b) = (self.den / g, other.den / g);
+        let num = mul(self.num, b)?.checked_add(mul(other.num, a)?);
+        Rational::new(num.ok_or(Overflow)?, mul(self.den, b)?)
+    }
+
+    pub fn checked_sub(self, other: Rational) -> Checked<Rational> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    /// Product with common factors cancelled crosswise before multiplying
+    pub fn checked_mul(self, other: Rational) -> Checked<Rational> {
+        let g1 = gcd(self.num, other.den)?.max(1);
+        let g2 = gcd(other.num, self.den)?.max(1);
+        Rational::new(
+            mul(self.num / g1, other.num / g2)?,
+            mul(self.den / g2, other.den / g1)?,
+        )
+    }
+
+    pub fn checked_div(self, other: Rational) -> Checked<Rational> {
+        if other.is_zero() {
+            return Err(Overflow);
+        }
+        self.checked_mul(Rational::new(other.den, other.num)?)
+    }
+}
+
+fn mul(a: i128, b: i128) -> Checked<i128> {
+    a.checked_mul(b).ok_or(Overflow)
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Rational {
+        Rational {
+            num: n.into(),
+            den: 1,
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl Ord for Rational {
+    /// Compare by continued fraction expansion, which needs no
+    /// multiplication and so cannot overflow
+    fn cmp(&self, other: &Rational) -> Ordering {
+        let (mut a, mut b) = (self.num, self.den);
+        let (mut c, mut d) = (other.num, other.den);
+        loop {
+            let (q1, r1) = (a.div_euclid(b), a.rem_euclid(b));
+            let (q2, r2) = (c.div_euclid(d), c.rem_euclid(d));
+            if q1 != q2 {
+                return q1.cmp(&q2);
+            }
+            match (r1 == 0, r2 == 0) {
+                (true, true) => return Ordering::Equal,
+                (true, false) => return Ordering::Less,
+                (false, true) => return Ordering::Greater,
+                // r1/b < r2/d exactly when d/r2 < b/r1
+                (false, false) => (a, b, c, d) = (d, r2, b, r1),
+            }
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(num: i128, den: i128) -> Rational {
+        Rational::new(num, den).unwrap()
+    }
+
+    #[test]
+    fn normalizes() {
+        assert_eq!(r(4, -6), r(-2, 3));
+        assert_eq!(r(-2, 3).to_string(), "-2/3");
+        assert_eq!((r(-7, 2).floor(), r(-7, 2).ceil()), (-4, -3));
+        assert_eq!(r(1, 6).checked_add(r(1, 3)), Ok(r(1, 2)));
+        assert_eq!(r(2, 3).checked_div(r(4, 9)), Ok(r(3, 2)));
+    }
+
+    #[test]
+    fn compares_without_overflow() {
+        let big = i128::MAX;
+        assert!(r(big - 1, big) < r(big, big - 1));
+        assert!(r(-big, 3) < r(1, big));
+        assert!(r(2, 7) > r(1, 4));
+        assert_eq!(r(3, 9).cmp(&r(1, 3)), Ordering::Equal);
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let big = Rational::from(i64::MAX);
+        let huge = big.checked_mul(big).unwrap();
+        assert_eq!(huge.checked_mul(big), Err(Overflow));
+        assert_eq!(
+            r(1, i128::MAX).checked_add(r(1, i128::MAX - 1)),
+            Err(Overflow)
+        );
+        assert_eq!(big.checked_div(Rational::ZERO), Err(Overflow));
+    }
+}
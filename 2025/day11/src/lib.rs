@@ -0,0 +1,231 @@
+//! Day 11: Reactor
+
+use common::{Answer, Error, Result, Solution};
+use std::collections::HashMap;
+
+/// Parse the device graph: `device: output output...`
+pub fn parse_input(input: &str) -> Result<HashMap<String, Vec<String>>> {
+    let mut graph = HashMap::new();
+
+    for line in input.trim().lines() {
+        let Some((device, outputs)) = line.split_once(':') else {
+            return Err(Error::parse_at(input, line, "expected 'device: outputs'"));
+        };
+        let device = device.trim();
+        if device.is_empty() || device.contains(char::is_whitespace) {
+            return Err(Error::parse_at(
+                input,
+                line,
+                format!("invalid device name '{}'", device),
+            ));
+        }
+        let outputs: Vec<String> = outputs.split_whitespace().map(|s| s.to_string()).collect();
+        graph.insert(device.to_string(), outputs);
+    }
+
+    Ok(graph)
+}
+
+/// Fail early when the path's starting device is not in the graph
+fn require_device(graph: &HashMap<String, Vec<String>>, device: &str) -> Result<()> {
+    if graph.contains_key(device) {
+        Ok(())
+    } else {
+        Err(Error::unsolvable(format!("no device named '{}'", device)))
+    }
+}
+
+/// Count distinct paths from `current` to `target` that never revisit a device
+pub fn count_paths(
+    graph: &HashMap<String, Vec<String>>,
+    current: &str,
+    target: &str,
+    visited: &mut Vec<String>,
+) -> usize {
+    // If we've reached the target, we found one path
+    if current == target {
+        return 1;
+    }
+
+    // Check if we're in a cycle
+    if visited.contains(&current.to_string()) {
+        return 0;
+    }
+
+    // Mark current node as visited
+    visited.push(current.to_string());
+
+    let mut total_paths = 0;
+
+    // Explore all neighbors
+    if let Some(neighbors) = graph.get(current) {
+        for neighbor in neighbors {
+            total_paths += count_paths(graph, neighbor, target, visited);
+        }
+    }
+
+    // Backtrack: remove current node from visited
+    visited.pop();
+
+    total_paths
+}
+
+/// State for memoization: (current_node, visited_dac, visited_fft)
+pub type MemoKey = (String, bool, bool);
+
+/// Count paths from `current` to `target` that pass through both `dac` and `fft`
+pub fn count_paths_with_required_memoized(
+    graph: &HashMap<String, Vec<String>>,
+    current: &str,
+    target: &str,
+    visited_dac: bool,
+    visited_fft: bool,
+    visited: &mut Vec<String>,
+    memo: &mut HashMap<MemoKey, usize>,
+) -> usize {
+    // If we've reached the target, check if we've visited all required nodes
+    if current == target {
+        return if visited_dac && visited_fft { 1 } else { 0 };
+    }
+
+    // Check if we're in a cycle
+    if visited.contains(&current.to_string()) {
+        return 0;
+    }
+
+    // Check memoization
+    let key = (current.to_string(), visited_dac, visited_fft);
+    if let Some(&result) = memo.get(&key) {
+        return result;
+    }
+
+    // Mark current node as visited
+    visited.push(current.to_string());
+
+    // Update visited flags
+    let new_visited_dac = visited_dac || current == "dac";
+    let new_visited_fft = visited_fft || current == "fft";
+
+    let mut total_paths = 0;
+
+    // Explore all neighbors
+    if let Some(neighbors) = graph.get(current) {
+        for neighbor in neighbors {
+            total_paths += count_paths_with_required_memoized(
+                graph,
+                neighbor,
+                target,
+                new_visited_dac,
+                new_visited_fft,
+                visited,
+                memo,
+            );
+        }
+    }
+
+    // Backtrack: remove current node from visited
+    visited.pop();
+
+    // Store in memo (only if not in current path to avoid caching cycle-dependent results)
+    memo.insert(key, total_paths);
+
+    total_paths
+}
+
+/// Number of paths from `you` to `out`
+pub fn solve_part1(graph: &HashMap<String, Vec<String>>) -> Result<usize> {
+    require_device(graph, "you")?;
+    let mut visited = Vec::new();
+    Ok(count_paths(graph, "you", "out", &mut visited))
+}
+
+/// Number of paths from `svr` to `out` visiting both `dac` and `fft`
+pub fn solve_part2(graph: &HashMap<String, Vec<String>>) -> Result<usize> {
+    require_device(graph, "svr")?;
+    let mut visited = Vec::new();
+    let mut memo = HashMap::new();
+    Ok(count_paths_with_required_memoized(
+        graph,
+        "svr",
+        "out",
+        false,
+        false,
+        &mut visited,
+        &mut memo,
+    ))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    const EXAMPLE: &'static str = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+";
+
+    const EXAMPLE_PART2: &'static str = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+    type Input = HashMap<String, Vec<String>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(graph: &Self::Input) -> Result<Answer> {
+        Ok(solve_part1(graph)?.into())
+    }
+
+    fn part2(graph: &Self::Input) -> Result<Answer> {
+        Ok(solve_part2(graph)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part};
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day11>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(5));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from(2));
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day11>();
+    }
+}
@@ -0,0 +1,430 @@
+//! Day 12: Christmas Tree Farm
+
+use common::{Answer, Error, Grid, Pos, Result, Solution};
+use std::collections::HashSet;
+
+/// Cells covered by a present, as (row, col) offsets
+pub type Shape = Vec<(i32, i32)>;
+
+/// Region to fill: (width, height, count of each shape)
+pub type Region = (usize, usize, Vec<usize>);
+
+/// Collect the `#` cells of a present shape, normalized to the origin
+pub fn parse_shape(lines: &[&str]) -> Shape {
+    let mut shape = Vec::new();
+    for (row, line) in lines.iter().enumerate() {
+        for (col, ch) in line.chars().enumerate() {
+            if ch == '#' {
+                shape.push((row as i32, col as i32));
+            }
+        }
+    }
+    normalize_shape(&shape)
+}
+
+/// Translate a shape so its top-left cell is at the origin, cells sorted
+pub fn normalize_shape(shape: &Shape) -> Shape {
+    if shape.is_empty() {
+        return shape.clone();
+    }
+    let min_row = shape.iter().map(|(r, _)| *r).min().unwrap();
+    let min_col = shape.iter().map(|(_, c)| *c).min().unwrap();
+    let mut normalized: Shape = shape
+        .iter()
+        .map(|(r, c)| (r - min_row, c - min_col))
+        .collect();
+    normalized.sort();
+    normalized
+}
+
+/// Rotate a shape a quarter turn
+pub fn rotate_90(shape: &Shape) -> Shape {
+    // (r, c) -> (c, -r)
+    let rotated: Shape = shape.iter().map(|(r, c)| (*c, -r)).collect();
+    normalize_shape(&rotated)
+}
+
+/// Mirror a shape left to right
+pub fn flip_horizontal(shape: &Shape) -> Shape {
+    // (r, c) -> (r, -c)
+    let flipped: Shape = shape.iter().map(|(r, c)| (*r, -c)).collect();
+    normalize_shape(&flipped)
+}
+
+/// All distinct rotations and reflections of a shape
+pub fn get_all_orientations(shape: &Shape) -> Vec<Shape> {
+    let mut orientations = HashSet::new();
+    let mut current = shape.clone();
+
+    for _ in 0..4 {
+        orientations.insert(current.clone());
+        orientations.insert(flip_horizontal(&current));
+        current = rotate_90(&current);
+    }
+
+    orientations.into_iter().collect()
+}
+
+/// Parse the present shapes (with every orientation) and the regions under the trees
+pub fn parse_input(input: &str) -> Result<(Vec<Vec<Shape>>, Vec<Region>)> {
+    let parts: Vec<&str> = input.trim().split("\n\n").collect();
+
+    let mut shapes: std::collections::HashMap<usize, Shape> = std::collections::HashMap::new();
+
+    // Parse shapes section
+    for part in &parts {
+        let lines: Vec<&str> = part.lines().collect();
+        if lines.is_empty() {
+            continue;
+        }
+
+        // Check if it's a shape definition (starts with "digit:")
+        if let Some(first_line) = lines.first() {
+            if let Some(colon_pos) = first_line.find(':') {
+                let potential_idx = &first_line[..colon_pos];
+                if let Ok(idx) = potential_idx.trim().parse::<usize>() {
+                    // This is a shape definition
+                    let shape_lines: Vec<&str> = if first_line.len() > colon_pos + 1
+                        && !first_line[colon_pos + 1..].trim().is_empty()
+                    {
+                        // Shape starts on same line
+                        let mut sl = vec![first_line[colon_pos + 1..].trim()];
+                        sl.extend(lines[1..].iter().copied());
+                        sl
+                    } else {
+                        lines[1..].to_vec()
+                    };
+                    for line in &shape_lines {
+                        if let Some(bad) = line.trim().split(['#', '.']).find(|s| !s.is_empty()) {
+                            return Err(Error::parse_at(
+                                input,
+                                bad,
+                                format!("unexpected '{}' in shape {}", bad, idx),
+                            ));
+                        }
+                    }
+                    let shape = parse_shape(&shape_lines);
+                    shapes.insert(idx, shape);
+                }
+            }
+        }
+    }
+
+    // Convert shapes to vector with all orientations
+    let max_shape_idx = shapes.keys().max().copied().unwrap_or(0);
+    let mut all_shapes: Vec<Vec<Shape>> = Vec::new();
+    for i in 0..=max_shape_idx {
+        if let Some(shape) = shapes.get(&i) {
+            all_shapes.push(get_all_orientations(shape));
+        } else {
+            all_shapes.push(vec![]);
+        }
+    }
+
+    // Parse regions
+    let mut regions: Vec<Region> = Vec::new();
+
+    for part in &parts {
+        let lines: Vec<&str> = part.lines().collect();
+        for line in lines {
+            // Check if it's a region definition (contains "x" and ":")
+            if let Some(x_pos) = line.find('x') {
+                if let Some(colon_pos) = line.find(':') {
+                    if x_pos < colon_pos {
+                        let width = parse_dimension(input, line[..x_pos].trim())?;
+                        let height = parse_dimension(input, line[x_pos + 1..colon_pos].trim())?;
+                        let mut counts = Vec::new();
+                        for (shape_idx, s) in line[colon_pos + 1..].split_whitespace().enumerate() {
+                            let count: usize = s.parse().map_err(|_| {
+                                Error::parse_at(input, s, format!("invalid present count '{}'", s))
+                            })?;
+                            if count > 0 && all_shapes.get(shape_idx).is_none_or(|o| o.is_empty()) {
+                                return Err(Error::parse_at(
+                                    input,
+                                    s,
+                                    format!("no shape {} to place", shape_idx),
+                                ));
+                            }
+                            counts.push(count);
+                        }
+                        regions.push((width, height, counts));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok((all_shapes, regions))
+}
+
+/// Parse a region width or height, which must be positive
+fn parse_dimension(input: &str, s: &str) -> Result<usize> {
+    match s.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(Error::parse_at(
+            input,
+            s,
+            format!("invalid region size '{}'", s),
+        )),
+    }
+}
+
+/// A shape's cells as unsigned offsets, which normalized shapes always have
+pub type Offsets = Vec<(usize, usize)>;
+
+/// The offsets of a normalized shape
+pub fn offsets(shape: &Shape) -> Offsets {
+    shape
+        .iter()
+        .map(|&(r, c)| (r as usize, c as usize))
+        .collect()
+}
+
+/// Whether a shape fits at `at` without overlapping. `at` must keep the shape
+/// inside the region, as every position from [`precompute_placements`] does.
+pub fn can_place(grid: &Grid<bool>, cells: &[(usize, usize)], at: Pos) -> bool {
+    cells
+        .iter()
+        .all(|&(dr, dc)| !grid[Pos::new(at.row + dr, at.col + dc)])
+}
+
+/// Mark the cells covered by a shape as occupied
+pub fn place_shape(grid: &mut Grid<bool>, cells: &[(usize, usize)], at: Pos) {
+    for &(dr, dc) in cells {
+        grid[Pos::new(at.row + dr, at.col + dc)] = true;
+    }
+}
+
+/// Free the cells covered by a shape
+pub fn remove_shape(grid: &mut Grid<bool>, cells: &[(usize, usize)], at: Pos) {
+    for &(dr, dc) in cells {
+        grid[Pos::new(at.row + dr, at.col + dc)] = false;
+    }
+}
+
+/// Precompute all valid placements for each shape and orientation
+pub struct Placements {
+    /// For each shape index, list of (orientation_ref, position)
+    pub data: Vec<Vec<(usize, Pos)>>,
+    /// For each shape index, the offsets of each orientation
+    pub cells: Vec<Vec<Offsets>>,
+}
+
+/// Every in-bounds `(orientation, position)` for each shape in a `width` x `height` region
+pub fn precompute_placements(all_shapes: &[Vec<Shape>], width: usize, height: usize) -> Placements {
+    let mut data = Vec::new();
+    let mut cells = Vec::new();
+
+    for shape_orientations in all_shapes {
+        let mut shape_placements = Vec::new();
+        let mut shape_cells = Vec::new();
+        for (orient_idx, orientation) in shape_orientations.iter().enumerate() {
+            let orientation = offsets(orientation);
+            let rows = orientation.iter().map(|&(r, _)| r + 1).max().unwrap_or(1);
+            let cols = orientation.iter().map(|&(_, c)| c + 1).max().unwrap_or(1);
+
+            for r in 0..height.saturating_sub(rows - 1) {
+                for c in 0..width.saturating_sub(cols - 1) {
+                    shape_placements.push((orient_idx, Pos::new(r, c)));
+                }
+            }
+            shape_cells.push(orientation);
+        }
+        data.push(shape_placements);
+        cells.push(shape_cells);
+    }
+
+    Placements { data, cells }
+}
+
+/// Backtracking search placing `pieces[piece_idx..]` into the grid
+pub fn solve(
+    grid: &mut Grid<bool>,
+    pieces: &mut Vec<usize>,
+    piece_idx: usize,
+    placements: &Placements,
+    last_placement: &mut Vec<usize>,
+) -> bool {
+    if piece_idx >= pieces.len() {
+        return true;
+    }
+
+    let shape_idx = pieces[piece_idx];
+
+    // If this piece is the same type as the previous one, start from the last placement index
+    // to avoid duplicate orderings
+    let start_idx = if piece_idx > 0 && pieces[piece_idx - 1] == shape_idx {
+        last_placement[piece_idx - 1]
+    } else {
+        0
+    };
+
+    let shape_placements = &placements.data[shape_idx];
+    let orientations = &placements.cells[shape_idx];
+
+    for (placement_idx, &(orient_idx, at)) in shape_placements.iter().enumerate().skip(start_idx) {
+        let orientation = &orientations[orient_idx];
+        if can_place(grid, orientation, at) {
+            place_shape(grid, orientation, at);
+            last_placement[piece_idx] = placement_idx;
+
+            if solve(grid, pieces, piece_idx + 1, placements, last_placement) {
+                return true;
+            }
+
+            remove_shape(grid, orientation, at);
+        }
+    }
+
+    false
+}
+
+/// Whether all requested presents fit in a `width` x `height` region
+pub fn can_fit_region(
+    width: usize,
+    height: usize,
+    counts: &[usize],
+    all_shapes: &[Vec<Shape>],
+) -> bool {
+    let size = |shape_idx: usize| all_shapes[shape_idx].first().map_or(0, |s| s.len());
+
+    // Quick area check, before building one entry per present, so huge
+    // counts are turned down without allocating for them
+    let total_area = counts
+        .iter()
+        .enumerate()
+        .try_fold(0usize, |area, (shape_idx, &count)| {
+            area.checked_add(count.checked_mul(size(shape_idx))?)
+        });
+    match (total_area, width.checked_mul(height)) {
+        (Some(total_area), Some(region_area)) if total_area <= region_area => {}
+        _ => return false,
+    }
+
+    // Build list of shape indices to place (one entry per piece); shapes
+    // without cells fit anywhere
+    let mut pieces: Vec<usize> = Vec::new();
+    for (shape_idx, &count) in counts.iter().enumerate() {
+        if size(shape_idx) > 0 {
+            pieces.extend(std::iter::repeat_n(shape_idx, count));
+        }
+    }
+
+    // Sort by shape size (larger first) for better pruning
+    pieces.sort_by_key(|&shape_idx| std::cmp::Reverse(size(shape_idx)));
+
+    if pieces.is_empty() {
+        return true;
+    }
+
+    let mut grid = Grid::new(width, height, false);
+    let placements = precompute_placements(all_shapes, width, height);
+    let mut last_placement = vec![0; pieces.len()];
+
+    solve(&mut grid, &mut pieces, 0, &placements, &mut last_placement)
+}
+
+/// Count regions that can fit all of their presents
+pub fn solve_part1(all_shapes: &[Vec<Shape>], regions: &[Region]) -> usize {
+    let mut count = 0;
+    for (width, height, counts) in regions {
+        if can_fit_region(*width, *height, counts, all_shapes) {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// There is no second puzzle on the last day
+pub fn solve_part2(_all_shapes: &[Vec<Shape>], _regions: &[Region]) -> String {
+    // Part 2 is awarded for completing Part 1 - puzzle complete!
+    "Puzzle complete!".to_string()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    const EXAMPLE: &'static str = "\
+0:
+###
+##.
+##.
+
+1:
+###
+##.
+.##
+
+2:
+.##
+###
+##.
+
+3:
+##.
+###
+##.
+
+4:
+###
+#..
+###
+
+5:
+###
+.#.
+###
+
+4x4: 0 0 0 0 2 0
+12x5: 1 0 1 0 2 2
+12x5: 1 0 1 0 3 2
+";
+
+    type Input = (Vec<Vec<Shape>>, Vec<Region>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((all_shapes, regions): &Self::Input) -> Result<Answer> {
+        Ok(solve_part1(all_shapes, regions).into())
+    }
+
+    fn part2((all_shapes, regions): &Self::Input) -> Result<Answer> {
+        Ok(solve_part2(all_shapes, regions).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part};
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day12>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(2));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from("Puzzle complete!"));
+    }
+
+    #[test]
+    fn huge_counts_are_turned_down_by_area() {
+        let (all_shapes, regions) = parse_input("0:\n##\n\n3x3: 18446744073709551615\n").unwrap();
+        assert_eq!(solve_part1(&all_shapes, &regions), 0);
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day12>();
+    }
+}
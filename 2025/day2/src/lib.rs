@@ -0,0 +1,446 @@
+//! Day 2: Gift Shop
+
+use common::{Answer, Error, Result, Solution};
+use std::fmt;
+
+/// Which IDs are invalid: those whose digits in `base` are one pattern
+/// written between `min_repeats` and `max_repeats` times
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RepeatRule {
+    pub min_repeats: u32,
+    pub max_repeats: u32,
+    pub base: u32,
+}
+
+impl RepeatRule {
+    /// Part 1: a decimal pattern repeated exactly twice
+    pub const PART1: RepeatRule = RepeatRule {
+        min_repeats: 2,
+        max_repeats: 2,
+        base: 10,
+    };
+
+    /// Part 2: a decimal pattern repeated at least twice
+    pub const PART2: RepeatRule = RepeatRule {
+        min_repeats: 2,
+        max_repeats: u32::MAX,
+        base: 10,
+    };
+
+    /// A rule repeating at least twice, in a base of at least 2
+    pub fn new(min_repeats: u32, max_repeats: u32, base: u32) -> Result<RepeatRule> {
+        if min_repeats < 2 || max_repeats < min_repeats {
+            return Err(Error::Usage(format!(
+                "Invalid repeats: {}..{}. Use at least 2 and a maximum no less than the minimum",
+                min_repeats, max_repeats
+            )));
+        }
+        if base < 2 {
+            return Err(Error::Usage(format!(
+                "Invalid base: {}. Use at least 2",
+                base
+            )));
+        }
+        Ok(RepeatRule {
+            min_repeats,
+            max_repeats,
+            base,
+        })
+    }
+
+    fn allows(&self, repeats: u32) -> bool {
+        (self.min_repeats..=self.max_repeats).contains(&repeats)
+    }
+
+    /// Check one ID digit by digit
+    pub fn is_invalid(&self, id: u64) -> bool {
+        let base = u64::from(self.base);
+        let mut digits = Vec::new();
+        let mut rest = id;
+        loop {
+            digits.push(rest % base);
+            rest /= base;
+            if rest == 0 {
+                break;
+            }
+        }
+
+        let len = digits.len();
+        (1..=len).any(|period| {
+            len.is_multiple_of(period)
+                && u32::try_from(len / period).is_ok_and(|repeats| self.allows(repeats))
+                && (period..len).all(|i| digits[i] == digits[i - period])
+        })
+    }
+
+    /// The pattern multiplier `1 0..01 0..01...`: a `period` digit pattern
+    /// times this is the pattern written `repeats` times
+    fn repunit(&self, period: u32, repeats: u32) -> u128 {
+        let base = u128::from(self.base);
+        (0..repeats).map(|i| base.pow(period * i)).sum()
+    }
+
+    /// Number of digits of `n`
+    fn digits(&self, n: u128) -> u32 {
+        n.checked_ilog(self.base.into()).map_or(1, |log| log + 1)
+    }
+
+    /// Pattern lengths the rule allows in `digits` digit numbers
+    fn periods(&self, digits: u32) -> Vec<u32> {
+        (1..=digits)
+            .filter(|&period| digits.is_multiple_of(period) && self.allows(digits / period))
+            .collect()
+    }
+
+    /// Range of `period` digit patterns whose repetition to `digits` digits
+    /// falls in `start..=end`, with their multiplier
+    fn patterns(&self, start: u128, end: u128, digits: u32, period: u32) -> (u128, u128, u128) {
+        let base = u128::from(self.base);
+        let multiplier = self.repunit(period, digits / period);
+        let first = base.pow(period - 1).max(start.div_ceil(multiplier));
+        let last = (base.pow(period) - 1).min(end / multiplier);
+        (first, last, multiplier)
+    }
+
+    /// Sum of the `digits` digit numbers in `start..=end` that repeat some
+    /// `period` digit pattern, including those that also repeat a shorter one
+    fn periodic_sum(&self, start: u128, end: u128, digits: u32, period: u32) -> u128 {
+        let (first, last, multiplier) = self.patterns(start, end, digits, period);
+        if first > last {
+            return 0;
+        }
+        (first + last) * (last - first + 1) / 2 * multiplier
+    }
+
+    /// Sum of the invalid IDs in `start..=end`, in time independent of the
+    /// range's width.
+    ///
+    /// A number repeating a pattern of `e` digits also repeats every pattern
+    /// whose length `e` divides, so numbers are counted by their shortest
+    /// pattern: inclusion–exclusion over the divisors of the length.
+    pub fn sum(&self, start: u64, end: u64) -> u128 {
+        let (start, end) = (u128::from(start), u128::from(end));
+        let mut total = 0;
+
+        for length in self.digits(start)..=self.digits(end) {
+            let periods = self.periods(length);
+            if periods.is_empty() {
+                continue;
+            }
+
+            // Sum of the numbers whose shortest pattern has each length
+            let divisors: Vec<u32> = (1..=length).filter(|&d| length.is_multiple_of(d)).collect();
+            let mut shortest = vec![0u128; divisors.len()];
+            for (i, &d) in divisors.iter().enumerate() {
+                let longer_patterns: u128 = (0..i)
+                    .filter(|&j| d.is_multiple_of(divisors[j]))
+                    .map(|j| shortest[j])
+                    .sum();
+                shortest[i] = self.periodic_sum(start, end, length, d) - longer_patterns;
+            }
+
+            for (i, &d) in divisors.iter().enumerate() {
+                if periods.iter().any(|period| period.is_multiple_of(d)) {
+                    total += shortest[i];
+                }
+            }
+        }
+
+        total
+    }
+
+    /// Every invalid ID in `start..=end`, in increasing order. Builds them
+    /// from their patterns, so takes time in proportion to how many there are.
+    pub fn ids(&self, start: u64, end: u64) -> Vec<u64> {
+        let (start, end) = (u128::from(start), u128::from(end));
+        let mut ids = Vec::new();
+
+        for length in self.digits(start)..=self.digits(end) {
+            let periods = self.periods(length);
+            for (i, &period) in periods.iter().enumerate() {
+                let (first, last, multiplier) = self.patterns(start, end, length, period);
+                for pattern in first..=last {
+                    let id = pattern * multiplier;
+                    // Leave numbers with a shorter allowed pattern to that one
+                    let shorter = periods[..i]
+                        .iter()
+                        .any(|&p| id.is_multiple_of(self.repunit(p, length / p)));
+                    if !shorter {
+                        ids.push(id as u64);
+                    }
+                }
+            }
+        }
+
+        ids.sort_unstable();
+        ids
+    }
+}
+
+/// Sort `ranges` and merge those that overlap or touch, so no ID is in
+/// more than one
+pub fn merge(ranges: &[(u64, u64)]) -> Vec<(u64, u64)> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort_unstable();
+
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(sorted.len());
+    for (start, end) in sorted {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= last_end.saturating_add(1) => {
+                *last_end = (*last_end).max(end);
+            }
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Sum every ID in the ranges that `rule` flags as invalid, counting IDs in
+/// overlapping ranges once
+pub fn solve(ranges: &[(u64, u64)], rule: &RepeatRule) -> u128 {
+    merge(ranges)
+        .iter()
+        .map(|&(start, end)| rule.sum(start, end))
+        .sum()
+}
+
+/// A comma separated token of the input that is not a usable range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejected {
+    pub token: String,
+    /// Byte offset of the token in the input
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' at byte {} ({})",
+            self.token, self.offset, self.reason
+        )
+    }
+}
+
+/// The ranges of the input, and the tokens that were left out
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ranges {
+    pub ranges: Vec<(u64, u64)>,
+    pub rejected: Vec<Rejected>,
+}
+
+impl Ranges {
+    /// Fail on the first rejected token, listing all of them
+    pub fn strict(&self) -> Result<()> {
+        let Some(first) = self.rejected.first() else {
+            return Ok(());
+        };
+        let list: Vec<String> = self.rejected.iter().map(Rejected::to_string).collect();
+        Err(Error::parse(
+            first.line,
+            first.column,
+            format!(
+                "{} rejected tokens: {}",
+                self.rejected.len(),
+                list.join("; ")
+            ),
+        ))
+    }
+
+    /// Warn about the rejected tokens, which the answer leaves out
+    pub fn warn_rejected(&self) {
+        if !self.rejected.is_empty() {
+            let list: Vec<String> = self.rejected.iter().map(Rejected::to_string).collect();
+            common::warn(format!(
+                "skipped {} tokens: {}",
+                self.rejected.len(),
+                list.join("; ")
+            ));
+        }
+    }
+}
+
+/// Parse comma separated `start-end` ranges. Tokens that are not ranges of
+/// IDs, or whose end is before their start, are set aside as rejected.
+pub fn parse_input(input: &str) -> Ranges {
+    let mut ranges = Ranges::default();
+
+    for token in input.split(',') {
+        let token = token.trim();
+        if token.is_empty() {
+            continue;
+        }
+
+        let range = match token.split_once('-') {
+            None => Err("expected start-end".to_string()),
+            Some((start, end)) => match (start.parse::<u64>(), end.parse::<u64>()) {
+                (Ok(start), Ok(end)) if end < start => {
+                    Err("reversed range, end is before start".to_string())
+                }
+                (Ok(start), Ok(end)) => Ok((start, end)),
+                (Err(_), _) => Err(format!("invalid ID '{}'", start)),
+                (_, Err(_)) => Err(format!("invalid ID '{}'", end)),
+            },
+        };
+
+        match range {
+            Ok(range) => ranges.ranges.push(range),
+            Err(reason) => {
+                let (line, column) = common::locate(input, token);
+                ranges.rejected.push(Rejected {
+                    token: token.to_string(),
+                    offset: token.as_ptr() as usize - input.as_ptr() as usize,
+                    line,
+                    column,
+                    reason,
+                });
+            }
+        }
+    }
+
+    ranges
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    const EXAMPLE: &'static str = "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
+";
+
+    type Input = Ranges;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(ranges: &Self::Input) -> Result<Answer> {
+        ranges.warn_rejected();
+        Ok(solve(&ranges.ranges, &RepeatRule::PART1).into())
+    }
+
+    fn part2(ranges: &Self::Input) -> Result<Answer> {
+        ranges.warn_rejected();
+        Ok(solve(&ranges.ranges, &RepeatRule::PART2).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part};
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day2>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(1227775554u64));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from(4174379265u64));
+    }
+
+    fn check_against(start: u64, end: u64, rule: RepeatRule) {
+        let expected: Vec<u64> = (start..=end).filter(|&id| rule.is_invalid(id)).collect();
+        assert_eq!(rule.ids(start, end), expected, "{:?}", rule);
+        let sum: u64 = expected.iter().sum();
+        assert_eq!(rule.sum(start, end), u128::from(sum), "{:?}", rule);
+    }
+
+    #[test]
+    fn enumeration_matches_the_digit_check() {
+        let rules = [
+            RepeatRule::PART1,
+            RepeatRule::PART2,
+            RepeatRule::new(3, 3, 10).unwrap(),
+            RepeatRule::new(2, 4, 2).unwrap(),
+            RepeatRule::new(2, u32::MAX, 16).unwrap(),
+        ];
+        for (start, end) in [(1, 12_000), (95, 115), (998_000, 1_012_000)] {
+            for rule in rules {
+                check_against(start, end, rule);
+            }
+        }
+    }
+
+    #[test]
+    fn rules_read_digits_in_their_base() {
+        assert!(RepeatRule::PART1.is_invalid(123123));
+        assert!(!RepeatRule::PART1.is_invalid(111));
+        assert!(RepeatRule::PART2.is_invalid(111));
+        let thrice = RepeatRule::new(3, 3, 10).unwrap();
+        assert!(thrice.is_invalid(121212) && !thrice.is_invalid(1212));
+        let hex = RepeatRule::new(2, 2, 16).unwrap();
+        assert!(hex.is_invalid(0xabab) && !hex.is_invalid(4343));
+        assert!(RepeatRule::new(1, 2, 10).is_err());
+        assert!(RepeatRule::new(2, 2, 1).is_err());
+    }
+
+    #[test]
+    fn wide_ranges_cost_only_their_answers() {
+        let (start, end) = (10u64.pow(15), 10u64.pow(15) + 10u64.pow(12));
+        let ids = RepeatRule::PART2.ids(start, end);
+        assert_eq!(ids.len(), 10_000);
+        assert!(ids.iter().all(|&id| RepeatRule::PART2.is_invalid(id)));
+        let sum: u64 = ids.iter().sum();
+        assert_eq!(RepeatRule::PART2.sum(start, end), u128::from(sum));
+    }
+
+    #[test]
+    fn overlapping_ranges_count_once() {
+        assert_eq!(
+            merge(&[(95, 115), (11, 22), (20, 40), (41, 50), (100, 110)]),
+            vec![(11, 50), (95, 115)]
+        );
+        let ranges = parse_input("11-22,11-22,15-99");
+        assert_eq!(
+            solve(&ranges.ranges, &RepeatRule::PART1),
+            11 + 22 + 33 + 44 + 55 + 66 + 77 + 88 + 99
+        );
+    }
+
+    #[test]
+    fn malformed_tokens_are_set_aside() {
+        let input = "11-22,\n30-20, 5,x-9,95-115,";
+        let ranges = parse_input(input);
+        assert_eq!(ranges.ranges, vec![(11, 22), (95, 115)]);
+
+        let rejected: Vec<String> = ranges.rejected.iter().map(Rejected::to_string).collect();
+        assert_eq!(
+            rejected,
+            vec![
+                "'30-20' at byte 7 (reversed range, end is before start)",
+                "'5' at byte 14 (expected start-end)",
+                "'x-9' at byte 16 (invalid ID 'x')",
+            ]
+        );
+        assert_eq!((ranges.rejected[0].line, ranges.rejected[0].column), (2, 1));
+
+        let Err(Error::Parse {
+            line,
+            column,
+            message,
+        }) = ranges.strict()
+        else {
+            panic!("strict mode accepted rejected tokens");
+        };
+        assert_eq!((line, column), (2, 1));
+        assert!(message.starts_with("3 rejected tokens: '30-20' at byte 7 ("));
+        assert!(parse_input(Day2::EXAMPLE).strict().is_ok());
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day2>();
+    }
+}
@@ -1,102 +1,57 @@
-use std::fs;
+use common::{Args, DayOption, Error, Part, Result};
+use day2::{Day2, RepeatRule};
 
-fn is_invalid_id_part1(n: u64) -> bool {
-    let s = n.to_string();
-    let len = s.len();
+const REPEATS: DayOption = DayOption::value("--repeats", "n|min..|min..max");
+const BASE: DayOption = DayOption::value("--base", "n");
+const STRICT: DayOption = DayOption::flag("--strict");
 
-    // Must have even length to be a repeated pattern
-    if len % 2 != 0 {
-        return false;
-    }
-
-    let half = len / 2;
-    let first_half = &s[..half];
-    let second_half = &s[half..];
-
-    // Check if first half equals second half (repeated exactly twice)
-    first_half == second_half
-}
-
-fn is_invalid_id_part2(n: u64) -> bool {
-    let s = n.to_string();
-    let len = s.len();
-
-    // Try all possible pattern lengths (from 1 to len/2)
-    // Pattern must repeat at least twice
-    for pattern_len in 1..=len / 2 {
-        // Check if the length is divisible by pattern length
-        if len % pattern_len != 0 {
-            continue;
-        }
-
-        let pattern = &s[..pattern_len];
-        let mut is_valid_pattern = true;
+fn main() {
+    let args = Args::from_env_with::<Day2>(&[REPEATS, BASE, STRICT]);
+    let rule = rule(&args).unwrap_or_else(|error| error.exit());
 
-        // Check if the entire string is made of repetitions of this pattern
-        for i in (pattern_len..len).step_by(pattern_len) {
-            if &s[i..i + pattern_len] != pattern {
-                is_valid_pattern = false;
-                break;
+    args.report_with::<Day2>(
+        |ranges| {
+            if args.flag(STRICT.name) {
+                ranges.strict()?;
             }
-        }
-
-        if is_valid_pattern {
-            return true;
-        }
-    }
-
-    false
+            ranges.warn_rejected();
+            Ok(day2::solve(&ranges.ranges, &rule).into())
+        },
+        |result| println!("Sum of invalid IDs: {}", result),
+    );
 }
 
-fn solve(input: &str, check_fn: fn(u64) -> bool) -> u64 {
-    let ranges: Vec<&str> = input.trim().split(',').collect();
-    let mut total = 0u64;
-
-    for range in ranges {
-        let parts: Vec<&str> = range.trim().split('-').collect();
-        if parts.len() != 2 {
-            continue;
-        }
-
-        let start: u64 = parts[0].parse().unwrap();
-        let end: u64 = parts[1].parse().unwrap();
-
-        for id in start..=end {
-            if check_fn(id) {
-                total += id;
-            }
-        }
-    }
-
-    total
+/// The part's rule, with the repeat counts and base changed by `--repeats`
+/// and `--base`
+fn rule(args: &Args) -> Result<RepeatRule> {
+    let default = match args.part {
+        Part::One => RepeatRule::PART1,
+        Part::Two => RepeatRule::PART2,
+    };
+    let (min_repeats, max_repeats) = match args.value(REPEATS.name) {
+        Some(repeats) => parse_repeats(repeats)?,
+        None => (default.min_repeats, default.max_repeats),
+    };
+    let base = args.value_as(BASE.name)?.unwrap_or(default.base);
+    RepeatRule::new(min_repeats, max_repeats, base)
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-
-    if args.len() < 2 {
-        eprintln!("Usage: {} <part1|part2>", args[0]);
-        std::process::exit(1);
-    }
-
-    let part = &args[1];
-
-    let check_fn = match part.as_str() {
-        "part1" => is_invalid_id_part1 as fn(u64) -> bool,
-        "part2" => is_invalid_id_part2 as fn(u64) -> bool,
-        _ => {
-            eprintln!("Invalid part: {}. Use 'part1' or 'part2'", part);
-            std::process::exit(1);
-        }
+/// `n`, `min..` or `min..max`
+fn parse_repeats(repeats: &str) -> Result<(u32, u32)> {
+    let invalid = || {
+        Error::Usage(format!(
+            "Invalid repeats: {}. Use n, min.. or min..max",
+            repeats
+        ))
     };
-
-    match fs::read_to_string("input.txt") {
-        Ok(input) => {
-            let result = solve(&input, check_fn);
-            println!("Sum of invalid IDs: {}", result);
-        }
-        Err(error) => {
-            eprintln!("Error reading input.txt: {}", error);
+    let count = |s: &str| s.parse::<u32>().map_err(|_| invalid());
+
+    match repeats.split_once("..") {
+        Some((min, "")) => Ok((count(min)?, u32::MAX)),
+        Some((min, max)) => Ok((count(min)?, count(max)?)),
+        None => {
+            let n = count(repeats)?;
+            Ok((n, n))
         }
     }
 }
@@ -0,0 +1,93 @@
+//! Non-negative integers of any size in decimal, for joltages of more
+//! batteries than fit in a machine integer
+
+use common::Answer;
+use std::fmt;
+
+/// A non-negative integer as its decimal digits
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Decimal {
+    /// Least significant first, without leading zeros (zero has none)
+    digits: Vec<u8>,
+}
+
+impl Decimal {
+    /// The number written with `digits`, most significant first
+    pub fn from_digits(digits: &[u8]) -> Decimal {
+        let mut digits: Vec<u8> = digits.iter().rev().copied().collect();
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Decimal { digits }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// `self += other`
+    pub fn add(&mut self, other: &Decimal) {
+        if self.digits.len() < other.digits.len() {
+            self.digits.resize(other.digits.len(), 0);
+        }
+        let mut carry = 0;
+        for (i, digit) in self.digits.iter_mut().enumerate() {
+            let sum = *digit + other.digits.get(i).copied().unwrap_or(0) + carry;
+            *digit = sum % 10;
+            carry = sum / 10;
+        }
+        if carry > 0 {
+            self.digits.push(carry);
+        }
+    }
+
+    /// The value, if it fits
+    pub fn to_u128(&self) -> Option<u128> {
+        self.digits.iter().rev().try_fold(0u128, |n, &digit| {
+            n.checked_mul(10)?.checked_add(digit.into())
+        })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        for &digit in self.digits.iter().rev() {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&Decimal> for Answer {
+    /// A number when it fits, its decimal text otherwise
+    fn from(n: &Decimal) -> Answer {
+        match n.to_u128() {
+            Some(n) => Answer::from(n),
+            None => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_past_u128() {
+        let mut total = Decimal::from_digits(&[0, 0, 9, 9]);
+        assert_eq!(total.to_string(), "99");
+        total.add(&Decimal::from_digits(&[1]));
+        assert_eq!(total.to_u128(), Some(100));
+
+        let big = Decimal::from_digits(&[9; 40]);
+        let mut sum = big.clone();
+        sum.add(&big);
+        assert_eq!(sum.to_string(), format!("1{}8", "9".repeat(39)));
+        assert_eq!(sum.to_u128(), None);
+        assert_eq!(Answer::from(&sum), Answer::Text(sum.to_string()));
+        assert_eq!(Decimal::default().to_string(), "0");
+    }
+}
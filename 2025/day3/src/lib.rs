@@ -0,0 +1,308 @@
+//! Day 3: Lobby
+
+pub mod decimal;
+
+pub use decimal::Decimal;
+
+use common::{Answer, Error, Result, Solution};
+
+/// Batteries turned on in a bank
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    /// Positions of the batteries in the bank, in increasing order
+    pub indices: Vec<usize>,
+    /// Their joltage ratings, in the same order
+    pub digits: Vec<u8>,
+}
+
+impl Selection {
+    /// The joltage the bank produces: its batteries' digits in order
+    pub fn joltage(&self) -> Decimal {
+        Decimal::from_digits(&self.digits)
+    }
+}
+
+/// The `k` batteries of `bank` (a string of digits) whose digits, kept in
+/// order, form the largest number, or `None` if the bank has fewer than `k`
+/// or anything other than digits.
+///
+/// A monotonic stack: a battery replaces the smaller ones before it while
+/// enough batteries remain after it to still turn on `k`.
+pub fn max_subsequence_number(bank: &str, k: usize) -> Option<Selection> {
+    let digits: Vec<u8> = bank
+        .bytes()
+        .map(|b| b.is_ascii_digit().then(|| b - b'0'))
+        .collect::<Option<_>>()?;
+    let mut droppable = digits.len().checked_sub(k)?;
+    let mut stack: Vec<usize> = Vec::with_capacity(digits.len());
+
+    for (i, &digit) in digits.iter().enumerate() {
+        while droppable > 0
+            && let Some(&top) = stack.last()
+            && digits[top] < digit
+        {
+            stack.pop();
+            droppable -= 1;
+        }
+        stack.push(i);
+    }
+    stack.truncate(k);
+
+    Some(Selection {
+        digits: stack.iter().map(|&i| digits[i]).collect(),
+        indices: stack,
+    })
+}
+
+/// One line of the input: a bank's battery ratings as digits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bank {
+    /// Line of the input the bank is on, from 1
+    pub line: usize,
+    pub batteries: String,
+}
+
+/// Lines of the banks with fewer than `k` batteries
+pub fn short_banks(banks: &[Bank], k: usize) -> Vec<usize> {
+    banks
+        .iter()
+        .filter(|bank| bank.batteries.len() < k)
+        .map(|bank| bank.line)
+        .collect()
+}
+
+/// Report banks that cannot turn on `k` batteries: an error when `strict`,
+/// otherwise a warning that they produce nothing
+pub fn check_short_banks(banks: &[Bank], k: usize, strict: bool) -> Result<()> {
+    let short = short_banks(banks, k);
+    if short.is_empty() {
+        return Ok(());
+    }
+
+    let message = format!(
+        "{} banks have fewer than {} batteries, on lines {:?}",
+        short.len(),
+        k,
+        short
+    );
+    if strict {
+        return Err(Error::unsolvable(message));
+    }
+    common::warn(format!("{}; they produce nothing", message));
+    Ok(())
+}
+
+/// Total output joltage with `k` batteries on in each bank; banks with fewer
+/// batteries produce nothing
+pub fn solve(banks: &[Bank], k: usize) -> Decimal {
+    let mut total = Decimal::default();
+    for bank in banks {
+        if let Some(selection) = max_subsequence_number(&bank.batteries, k) {
+            total.add(&selection.joltage());
+        }
+    }
+    total
+}
+
+/// Total output joltage with two batteries per bank
+pub fn solve_part1(banks: &[Bank]) -> Result<Decimal> {
+    check_short_banks(banks, 2, false)?;
+    Ok(solve(banks, 2))
+}
+
+/// Total output joltage with twelve batteries per bank
+pub fn solve_part2(banks: &[Bank]) -> Result<Decimal> {
+    check_short_banks(banks, 12, false)?;
+    Ok(solve(banks, 12))
+}
+
+/// Parse one bank per line. Every line with a battery that is not a digit
+/// is reported, located at the first such line.
+pub fn parse_input(input: &str) -> Result<Vec<Bank>> {
+    let mut banks = Vec::new();
+    let mut invalid = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let batteries = line.trim();
+        if batteries.is_empty() {
+            continue;
+        }
+
+        match batteries.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            Some((at, c)) => invalid.push((i + 1, &batteries[at..], c)),
+            None => banks.push(Bank {
+                line: i + 1,
+                batteries: batteries.to_string(),
+            }),
+        }
+    }
+
+    if let Some(&(_, token, c)) = invalid.first() {
+        let mut message = format!("invalid battery '{}'", c);
+        if invalid.len() > 1 {
+            let lines: Vec<usize> = invalid[1..].iter().map(|&(line, _, _)| line).collect();
+            message += &format!("; lines {:?} have invalid batteries too", lines);
+        }
+        return Err(Error::parse_at(input, token, message));
+    }
+
+    Ok(banks)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    const EXAMPLE: &'static str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    type Input = Vec<Bank>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(banks: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(&solve_part1(banks)?))
+    }
+
+    fn part2(banks: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(&solve_part2(banks)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part, Random};
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day3>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(357u64));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from(3121910778619u64));
+    }
+
+    /// Best `k` digits by trying every choice, as an oracle
+    fn brute_force(digits: &[u8], k: usize) -> Option<Vec<u8>> {
+        if k == 0 {
+            return Some(Vec::new());
+        }
+        (0..digits.len())
+            .filter_map(|i| {
+                let mut rest = brute_force(&digits[i + 1..], k - 1)?;
+                rest.insert(0, digits[i]);
+                Some(rest)
+            })
+            .max()
+    }
+
+    #[test]
+    fn stack_matches_brute_force() {
+        let mut rng = Random::default();
+
+        for _ in 0..300 {
+            let bank: String = (0..rng.below(9))
+                .map(|_| char::from(b'0' + rng.below(10) as u8))
+                .collect();
+            let digits: Vec<u8> = bank.bytes().map(|b| b - b'0').collect();
+            for k in 0..=bank.len() + 1 {
+                let selection = max_subsequence_number(&bank, k);
+                assert_eq!(
+                    selection.as_ref().map(|s| s.digits.clone()),
+                    brute_force(&digits, k),
+                    "{} choosing {}",
+                    bank,
+                    k
+                );
+                if let Some(selection) = selection {
+                    assert!(selection.indices.windows(2).all(|w| w[0] < w[1]));
+                    assert!(
+                        selection
+                            .indices
+                            .iter()
+                            .zip(&selection.digits)
+                            .all(|(&i, &d)| digits[i] == d)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_banks_that_are_not_digits() {
+        for bank in ["12 3", "-12", "1,2", "\u{0663}4"] {
+            assert_eq!(max_subsequence_number(bank, 1), None, "{:?}", bank);
+        }
+        assert_eq!(max_subsequence_number("", 0).unwrap().digits, []);
+    }
+
+    #[test]
+    fn selects_more_digits_than_fit_in_u128() {
+        let bank = "9".repeat(20) + &"8".repeat(20) + "7";
+        let selection = max_subsequence_number(&bank, 40).unwrap();
+        assert_eq!(selection.indices, (0..40).collect::<Vec<_>>());
+        let banks = parse_input(&format!("{}\n{}\n", bank, bank)).unwrap();
+        let total = solve(&banks, 40);
+        assert_eq!(
+            Answer::from(&total),
+            Answer::Text(format!("1{}{}6", "9".repeat(20), "7".repeat(19)))
+        );
+        let example = max_subsequence_number("818181911112111", 2).unwrap();
+        assert_eq!(example.indices, vec![6, 11]);
+    }
+
+    #[test]
+    fn reports_every_invalid_line() {
+        let Err(Error::Parse {
+            line,
+            column,
+            message,
+        }) = parse_input("12345\n12x45\n\n999\n9-9\n1 2\n")
+        else {
+            panic!("invalid batteries were accepted");
+        };
+        assert_eq!((line, column), (2, 3));
+        assert_eq!(
+            message,
+            "invalid battery 'x'; lines [5, 6] have invalid batteries too"
+        );
+    }
+
+    #[test]
+    fn short_banks_are_reported() {
+        let banks = parse_input("987\n\n9\n12\n").unwrap();
+        assert_eq!(banks[1].line, 3);
+        assert_eq!(short_banks(&banks, 2), vec![3]);
+        assert_eq!(solve(&banks, 2).to_string(), "110");
+        assert!(matches!(
+            check_short_banks(&banks, 3, true),
+            Err(Error::Unsolvable(message)) if message == "2 banks have fewer than 3 batteries, on lines [3, 4]"
+        ));
+
+        assert!(check_short_banks(&banks, 3, false).is_ok());
+        let warnings = common::take_warnings();
+        assert_eq!(
+            warnings,
+            vec!["2 banks have fewer than 3 batteries, on lines [3, 4]; they produce nothing"]
+        );
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day3>();
+    }
+}
@@ -0,0 +1,105 @@
+//! Day 4: Printing Department
+
+use common::{Answer, Grid, Pos, Ragged, Result, Solution};
+
+/// Count paper rolls (`@`) in the 8 cells surrounding `pos`
+pub fn count_adjacent_rolls(grid: &Grid<char>, pos: Pos) -> usize {
+    grid.neighbours8(pos)
+        .filter(|&next| grid[next] == '@')
+        .count()
+}
+
+/// Positions of the rolls a forklift can reach (fewer than 4 neighbouring rolls)
+fn accessible_rolls(grid: &Grid<char>) -> Vec<Pos> {
+    grid.iter()
+        .filter(|&(pos, &cell)| cell == '@' && count_adjacent_rolls(grid, pos) < 4)
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+/// Count rolls reachable by a forklift
+pub fn solve_part1(grid: &Grid<char>) -> usize {
+    accessible_rolls(grid).len()
+}
+
+/// Repeatedly remove accessible rolls and count how many are removed in total
+pub fn solve_part2(grid: &Grid<char>) -> usize {
+    let mut grid = grid.clone();
+    let mut total_removed = 0;
+
+    loop {
+        let to_remove = accessible_rolls(&grid);
+
+        // If no rolls can be removed, stop
+        if to_remove.is_empty() {
+            break;
+        }
+
+        for &pos in &to_remove {
+            grid[pos] = '.';
+        }
+
+        total_removed += to_remove.len();
+    }
+
+    total_removed
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    const EXAMPLE: &'static str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, Ragged::Reject, Some)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(solve_part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        Ok(solve_part2(grid).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part};
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day4>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(13));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from(43));
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day4>();
+    }
+}
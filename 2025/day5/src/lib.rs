@@ -0,0 +1,169 @@
+//! Day 5: Cafeteria
+
+use common::{Answer, Error, Result, Solution};
+
+/// Whether `id` falls inside any of the inclusive fresh ranges
+pub fn is_fresh(id: u64, ranges: &[(u64, u64)]) -> bool {
+    for (start, end) in ranges {
+        if id >= *start && id <= *end {
+            return true;
+        }
+    }
+    false
+}
+
+/// Fresh ingredient ID ranges followed by the available ingredient IDs
+pub struct Inventory {
+    pub ranges: Vec<(u64, u64)>,
+    pub ids: Vec<u64>,
+}
+
+/// Parse the fresh ranges and the available IDs
+pub fn parse_inventory(input: &str) -> Result<Inventory> {
+    // Separate ranges (contain '-') from IDs (don't contain '-')
+    let mut ranges: Vec<(u64, u64)> = Vec::new();
+    let mut ids: Vec<u64> = Vec::new();
+
+    let id = |s: &str| {
+        s.parse::<u64>()
+            .map_err(|_| Error::parse_at(input, s, format!("invalid ID '{}'", s)))
+    };
+
+    for line in input.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some((start, end)) = trimmed.split_once('-') {
+            let (start, end) = (id(start)?, id(end)?);
+            if end < start {
+                return Err(Error::parse_at(
+                    input,
+                    trimmed,
+                    "range ends before it starts",
+                ));
+            }
+            ranges.push((start, end));
+        } else {
+            ids.push(id(trimmed)?);
+        }
+    }
+
+    Ok(Inventory { ranges, ids })
+}
+
+/// Count available ingredient IDs that are fresh
+pub fn solve_part1(inventory: &Inventory) -> usize {
+    // Count fresh ones
+    inventory
+        .ids
+        .iter()
+        .filter(|id| is_fresh(**id, &inventory.ranges))
+        .count()
+}
+
+/// Count distinct IDs covered by the fresh ranges. The whole `u64` range
+/// holds one ID more than `u64` can count, so the total is a `u128`.
+pub fn solve_part2(inventory: &Inventory) -> u128 {
+    let mut ranges = inventory.ranges.clone();
+
+    // Merge overlapping ranges to avoid double counting
+    if ranges.is_empty() {
+        return 0;
+    }
+
+    // Sort ranges by start position
+    ranges.sort_by_key(|r| r.0);
+
+    // Merge overlapping ranges
+    let mut merged: Vec<(u64, u64)> = Vec::new();
+    merged.push(ranges[0]);
+
+    for i in 1..ranges.len() {
+        let last_idx = merged.len() - 1;
+        let (last_start, last_end) = merged[last_idx];
+        let (curr_start, curr_end) = ranges[i];
+
+        // If ranges overlap or are adjacent, merge them
+        if curr_start <= last_end.saturating_add(1) {
+            merged[last_idx] = (last_start, last_end.max(curr_end));
+        } else {
+            merged.push((curr_start, curr_end));
+        }
+    }
+
+    // Count total IDs in merged ranges
+    let mut total = 0u128;
+    for (start, end) in merged {
+        total += u128::from(end - start) + 1;
+    }
+
+    total
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    const EXAMPLE: &'static str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_inventory(input)
+    }
+
+    fn part1(inventory: &Self::Input) -> Result<Answer> {
+        Ok(solve_part1(inventory).into())
+    }
+
+    fn part2(inventory: &Self::Input) -> Result<Answer> {
+        Ok(solve_part2(inventory).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part};
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day5>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(3));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from(14));
+    }
+
+    #[test]
+    fn counts_the_whole_id_range() {
+        let inventory = parse_inventory("0-18446744073709551615\n5-10\n").unwrap();
+        assert_eq!(solve_part2(&inventory), 1 << 64);
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day5>();
+    }
+}
@@ -0,0 +1,215 @@
+//! Day 6: Trash Compactor
+
+use common::{Answer, Error, Grid, Pos, Ragged, Result, Solution};
+
+/// Read the worksheet, padding every line with spaces to the same width
+pub fn parse_grid(input: &str) -> Result<Grid<char>> {
+    let grid = Grid::parse(input, Ragged::Pad(' '), |c| {
+        matches!(c, '0'..='9' | ' ' | '+' | '*').then_some(c)
+    })?;
+
+    if grid.is_empty() {
+        return Err(Error::parse(1, 1, "empty worksheet"));
+    }
+    Ok(grid)
+}
+
+/// Identify all problems (column ranges)
+pub fn find_problems(grid: &Grid<char>) -> Vec<(usize, usize)> {
+    let height = grid.height();
+    let width = grid.width();
+
+    // Identify separator columns
+    let mut is_sep = vec![false; width];
+    for c in 0..width {
+        is_sep[c] = (0..height).all(|r| grid[Pos::new(r, c)] == ' ');
+    }
+
+    // Split into ranges
+    let mut problems = Vec::new();
+    let mut c = 0;
+    while c < width {
+        if is_sep[c] {
+            c += 1;
+            continue;
+        }
+        let start = c;
+        while c < width && !is_sep[c] {
+            c += 1;
+        }
+        problems.push((start, c));
+    }
+
+    problems
+}
+
+/// Find the operator of the problem spanning columns `start..end`
+fn find_operator(grid: &Grid<char>, start: usize, end: usize) -> Result<char> {
+    let height = grid.height();
+    for col in start..end {
+        let ch = grid[Pos::new(height - 1, col)];
+        if ch == '+' || ch == '*' {
+            return Ok(ch);
+        }
+    }
+    Err(Error::parse(height, start + 1, "missing operator"))
+}
+
+/// Parse one number of a problem; `(row, col)` locates it for error messages
+fn parse_number(s: &str, row: usize, col: usize) -> Result<u128> {
+    s.trim()
+        .parse()
+        .map_err(|_| Error::parse(row + 1, col + 1, format!("invalid number '{}'", s.trim())))
+}
+
+/// Combine a problem's numbers with its operator
+fn evaluate(op: char, numbers: &[u128], start: usize) -> Result<u128> {
+    let Some((&first, rest)) = numbers.split_first() else {
+        return Err(Error::parse(1, start + 1, "problem has no numbers"));
+    };
+
+    let mut acc = Some(first);
+    match op {
+        '+' => {
+            for &n in rest {
+                acc = acc.and_then(|acc| acc.checked_add(n));
+            }
+        }
+        '*' => {
+            for &n in rest {
+                acc = acc.and_then(|acc| acc.checked_mul(n));
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    acc.ok_or_else(|| {
+        Error::overflow(format!(
+            "problem at column {} overflows 128 bits",
+            start + 1
+        ))
+    })
+}
+
+/// Solve Part 1
+pub fn solve_part1(grid: &Grid<char>) -> Result<u128> {
+    let height = grid.height();
+    let problems = find_problems(grid);
+
+    let mut grand: u128 = 0;
+
+    for (start, end) in problems {
+        let op = find_operator(grid, start, end)?;
+
+        // read numbers row by row
+        let mut numbers: Vec<u128> = Vec::new();
+        for row in 0..(height - 1) {
+            let mut s = String::new();
+            for col in start..end {
+                s.push(grid[Pos::new(row, col)]);
+            }
+            if !s.trim().is_empty() {
+                numbers.push(parse_number(&s, row, start)?);
+            }
+        }
+
+        grand = grand
+            .checked_add(evaluate(op, &numbers, start)?)
+            .ok_or_else(|| Error::overflow("grand total overflows 128 bits"))?;
+    }
+
+    Ok(grand)
+}
+
+/// Solve Part 2
+pub fn solve_part2(grid: &Grid<char>) -> Result<u128> {
+    let height = grid.height();
+    let problems = find_problems(grid);
+
+    let mut grand: u128 = 0;
+
+    for (start, end) in problems {
+        let op = find_operator(grid, start, end)?;
+
+        // read numbers column by column (top->bottom), then reverse order
+        let mut nums: Vec<u128> = Vec::new();
+        for col in start..end {
+            let mut s = String::new();
+            for row in 0..(height - 1) {
+                s.push(grid[Pos::new(row, col)]);
+            }
+            if !s.trim().is_empty() {
+                nums.push(parse_number(&s, 0, col)?);
+            }
+        }
+
+        nums.reverse(); // right-to-left order
+
+        grand = grand
+            .checked_add(evaluate(op, &nums, start)?)
+            .ok_or_else(|| Error::overflow("grand total overflows 128 bits"))?;
+    }
+
+    Ok(grand)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    const EXAMPLE: &'static str = "\
+123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +
+";
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(solve_part1(grid)?.into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        Ok(solve_part2(grid)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part};
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day6>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(4277556u128));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from(3263827u128));
+    }
+
+    #[test]
+    fn blank_lines_are_kept_as_rows() {
+        let grid = parse_grid("12 3\n\n 4 5\n+  *\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 4));
+        assert_eq!(grid.row(1), [' '; 4]);
+        assert_eq!(solve_part1(&grid).unwrap(), 16 + 15);
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day6>();
+    }
+}
@@ -0,0 +1,151 @@
+//! Day 7: Laboratories
+
+use common::{Answer, Error, Grid, Pos, Ragged, Result, Solution};
+use std::collections::{HashSet, VecDeque};
+
+/// Where a split beam continues: just left and right of the splitter
+const SIDEWAYS: [(isize, isize); 2] = [(0, -1), (0, 1)];
+
+/// Follow a beam down from `from` to the splitter it hits, `None` if it
+/// leaves the manifold first
+fn next_splitter(grid: &Grid<char>, from: Pos) -> Option<Pos> {
+    (from.row + 1..grid.height())
+        .map(|row| Pos::new(row, from.col))
+        .find(|&pos| grid[pos] == '^')
+}
+
+/// Count the splitters a tachyon beam hits on its way down
+pub fn solve_part1(grid: &Grid<char>) -> usize {
+    let Some(start) = grid.find(|&c| c == 'S') else {
+        return 0;
+    };
+
+    // Track which splitters have been hit
+    let mut split_splitters = HashSet::new();
+
+    // Track active beams, queuing each position once so merging beams are
+    // only followed once
+    let mut beams = VecDeque::from([start]);
+    let mut queued = Grid::new(grid.width(), grid.height(), false);
+    queued[start] = true;
+
+    while let Some(beam) = beams.pop_front() {
+        let Some(splitter) = next_splitter(grid, beam) else {
+            continue;
+        };
+        split_splitters.insert(splitter);
+
+        for next in grid.neighbours(splitter, &SIDEWAYS) {
+            if !queued[next] {
+                queued[next] = true;
+                beams.push_back(next);
+            }
+        }
+    }
+
+    split_splitters.len()
+}
+
+/// Count the timelines from a particle at `from` to the bottom, remembering
+/// the count for every position already seen
+fn count_timelines(grid: &Grid<char>, from: Pos, memo: &mut Grid<Option<usize>>) -> usize {
+    if let Some(count) = memo[from] {
+        return count;
+    }
+
+    let count = match next_splitter(grid, from) {
+        // Leaving the manifold completes one timeline
+        None => 1,
+        Some(splitter) => grid
+            .neighbours(splitter, &SIDEWAYS)
+            .map(|next| count_timelines(grid, next, memo))
+            .sum(),
+    };
+
+    memo[from] = Some(count);
+    count
+}
+
+/// Count the timelines a single tachyon particle can end up in
+pub fn solve_part2(grid: &Grid<char>) -> usize {
+    let Some(start) = grid.find(|&c| c == 'S') else {
+        return 0;
+    };
+
+    let mut memo = Grid::new(grid.width(), grid.height(), None);
+    count_timelines(grid, start, &mut memo)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    const EXAMPLE: &'static str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse(input, Ragged::Reject, Some)?;
+
+        if !grid.is_empty() && !grid.row(0).contains(&'S') {
+            return Err(Error::parse(
+                1,
+                1,
+                "no starting position 'S' in the first row",
+            ));
+        }
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(solve_part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        Ok(solve_part2(grid).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part};
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day7>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(21));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from(40));
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day7>();
+    }
+}
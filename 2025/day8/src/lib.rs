@@ -0,0 +1,279 @@
+//! Day 8: Playground
+
+use common::{Answer, Error, Result, Solution};
+use std::collections::HashMap;
+
+/// A junction box position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point {
+    pub fn distance_squared(&self, other: &Point) -> i64 {
+        let dx = self.x as i64 - other.x as i64;
+        let dy = self.y as i64 - other.y as i64;
+        let dz = self.z as i64 - other.z as i64;
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+/// Junction boxes, and how many of the closest pairs part 1 connects
+pub struct Playground {
+    pub points: Vec<Point>,
+    pub connections: usize,
+}
+
+/// Parse junction box positions, one `x,y,z` triple per line
+pub fn parse_points(input: &str) -> Result<Vec<Point>> {
+    let mut points = Vec::new();
+
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mut parts = Vec::new();
+        for s in line.split(',') {
+            let s = s.trim();
+            let coordinate = s
+                .parse::<i32>()
+                .map_err(|_| Error::parse_at(input, s, format!("invalid coordinate '{}'", s)))?;
+            parts.push(coordinate);
+        }
+
+        if parts.len() != 3 {
+            return Err(Error::parse_at(
+                input,
+                line,
+                format!("expected 3 coordinates, found {}", parts.len()),
+            ));
+        }
+
+        points.push(Point {
+            x: parts[0],
+            y: parts[1],
+            z: parts[2],
+        });
+    }
+
+    Ok(points)
+}
+
+/// Disjoint sets with path compression and union by size
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    /// `n` singleton sets
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    /// Representative of the set containing `x`
+    pub fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    /// Merge the sets containing `x` and `y`, returning false if already joined
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let root_x = self.find(x);
+        let root_y = self.find(y);
+
+        if root_x == root_y {
+            return false; // Already in same set
+        }
+
+        // Union by size
+        if self.size[root_x] < self.size[root_y] {
+            self.parent[root_x] = root_y;
+            self.size[root_y] += self.size[root_x];
+        } else {
+            self.parent[root_y] = root_x;
+            self.size[root_x] += self.size[root_y];
+        }
+
+        true
+    }
+
+    /// Size of every set
+    pub fn get_component_sizes(&mut self) -> Vec<usize> {
+        let mut sizes = HashMap::new();
+        for i in 0..self.parent.len() {
+            let root = self.find(i);
+            *sizes.entry(root).or_insert(0) += 1;
+        }
+        sizes.values().copied().collect()
+    }
+}
+
+/// Connect the `connections` closest pairs and multiply the sizes of the three largest circuits
+pub fn solve_part1(points: &[Point], connections: usize) -> usize {
+    let n = points.len();
+
+    // Calculate all pairwise distances
+    let mut edges = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dist = points[i].distance_squared(&points[j]);
+            edges.push((dist, i, j));
+        }
+    }
+
+    // Sort by distance
+    edges.sort_by_key(|e| e.0);
+
+    // Create union-find structure
+    let mut uf = UnionFind::new(n);
+
+    // Process the first 'connections' closest pairs (whether they connect or not)
+    for idx in 0..connections.min(edges.len()) {
+        let (_dist, i, j) = edges[idx];
+        uf.union(i, j);
+    }
+
+    // Get circuit sizes
+    let mut sizes = uf.get_component_sizes();
+    sizes.sort_by(|a, b| b.cmp(a)); // Sort descending
+
+    // Multiply the three largest
+    if sizes.len() >= 3 {
+        sizes[0] * sizes[1] * sizes[2]
+    } else {
+        0
+    }
+}
+
+/// Connect closest pairs until a single circuit remains and multiply the X coordinates of the last pair joined
+pub fn solve_part2(points: &[Point]) -> Result<i64> {
+    let n = points.len();
+
+    if n < 2 {
+        return Err(Error::unsolvable("at least two junction boxes are needed"));
+    }
+
+    // Calculate all pairwise distances
+    let mut edges = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dist = points[i].distance_squared(&points[j]);
+            edges.push((dist, i, j));
+        }
+    }
+
+    // Sort by distance
+    edges.sort_by_key(|e| e.0);
+
+    // Create union-find structure
+    let mut uf = UnionFind::new(n);
+
+    // Keep connecting until everything is in one circuit
+    let mut last_i = 0;
+    let mut last_j = 0;
+
+    for (_dist, i, j) in edges {
+        if uf.union(i, j) {
+            last_i = i;
+            last_j = j;
+
+            // Check if everything is connected
+            let sizes = uf.get_component_sizes();
+            if sizes.len() == 1 {
+                break;
+            }
+        }
+    }
+
+    // Multiply X coordinates
+    Ok(points[last_i].x as i64 * points[last_j].x as i64)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    const EXAMPLE: &'static str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+    type Input = Playground;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Playground {
+            points: parse_points(input)?,
+            connections: 1000,
+        })
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input> {
+        // The example only connects the ten closest pairs
+        Ok(Playground {
+            points: parse_points(input)?,
+            connections: 10,
+        })
+    }
+
+    fn part1(playground: &Self::Input) -> Result<Answer> {
+        Ok(solve_part1(&playground.points, playground.connections).into())
+    }
+
+    fn part2(playground: &Self::Input) -> Result<Answer> {
+        Ok(solve_part2(&playground.points)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part};
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day8>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(40));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from(25272));
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day8>();
+    }
+}
@@ -0,0 +1,463 @@
+//! Day 9: Movie Theater
+
+use common::{Answer, Error, Result, Solution};
+
+/// A tile position on the theater floor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Parse red tile positions, one `x,y` pair per line
+pub fn parse_tiles(input: &str) -> Result<Vec<Point>> {
+    let mut tiles = Vec::new();
+
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mut parts = Vec::new();
+        for s in line.split(',') {
+            let s = s.trim();
+            let coordinate = s
+                .parse::<i32>()
+                .map_err(|_| Error::parse_at(input, s, format!("invalid coordinate '{}'", s)))?;
+            parts.push(coordinate);
+        }
+
+        if parts.len() != 2 {
+            return Err(Error::parse_at(
+                input,
+                line,
+                format!("expected 2 coordinates, found {}", parts.len()),
+            ));
+        }
+
+        tiles.push(Point {
+            x: parts[0],
+            y: parts[1],
+        });
+    }
+
+    Ok(tiles)
+}
+
+/// Largest rectangle having two red tiles as opposite corners
+pub fn solve_part1(tiles: &[Point]) -> i64 {
+    let n = tiles.len();
+    let mut max_area: i64 = 0;
+
+    // Try all pairs of tiles as opposite corners
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let p1 = tiles[i];
+            let p2 = tiles[j];
+
+            // Calculate rectangle dimensions (add 1 to include both corner tiles)
+            let width = (p2.x as i64 - p1.x as i64).abs() + 1;
+            let height = (p2.y as i64 - p1.y as i64).abs() + 1;
+
+            // Area of rectangle
+            let area = width * height;
+
+            if area > max_area {
+                max_area = area;
+            }
+        }
+    }
+
+    max_area
+}
+
+/// Red and green tiles on a compressed grid: every red tile coordinate gets
+/// its own row or column, and the runs of coordinates between them share one.
+/// A compressed cell is then either entirely outside the loop or not at all.
+pub struct Floor {
+    xs: Vec<i32>,
+    ys: Vec<i32>,
+    /// `outside[r][c]`: tiles outside the loop in compressed rows `..r`, columns `..c`
+    outside: Vec<Vec<i64>>,
+}
+
+impl Floor {
+    /// Compress the loop through the red tiles, which must turn only at red
+    /// tiles: each tile shares a row or column with the next one
+    pub fn new(tiles: &[Point]) -> Result<Floor> {
+        let n = tiles.len();
+        for i in 0..n {
+            let (p1, p2) = (tiles[i], tiles[(i + 1) % n]);
+            if p1.x != p2.x && p1.y != p2.y {
+                return Err(Error::unsolvable(format!(
+                    "red tiles ({},{}) and ({},{}) are not in the same row or column",
+                    p1.x, p1.y, p2.x, p2.y
+                )));
+            }
+        }
+
+        let mut xs: Vec<i32> = tiles.iter().map(|p| p.x).collect();
+        let mut ys: Vec<i32> = tiles.iter().map(|p| p.y).collect();
+        xs.sort_unstable();
+        xs.dedup();
+        ys.sort_unstable();
+        ys.dedup();
+
+        // Coordinate i is cell 2i+1 and the gap after it is cell 2i+2, with
+        // an empty cell on each side so the outside is connected
+        let width = 2 * xs.len() + 1;
+        let height = 2 * ys.len() + 1;
+        let column = |x: i32| 2 * xs.binary_search(&x).unwrap_or_default() + 1;
+        let row = |y: i32| 2 * ys.binary_search(&y).unwrap_or_default() + 1;
+
+        let mut edge = vec![vec![false; width]; height];
+        for i in 0..n {
+            let (p1, p2) = (tiles[i], tiles[(i + 1) % n]);
+            let (c1, c2) = (column(p1.x), column(p2.x));
+            let (r1, r2) = (row(p1.y), row(p2.y));
+            for r in r1.min(r2)..=r1.max(r2) {
+                for c in c1.min(c2)..=c1.max(c2) {
+                    edge[r][c] = true;
+                }
+            }
+        }
+
+        // Flood the outside from the empty border
+        let mut is_outside = vec![vec![false; width]; height];
+        let mut stack = vec![(0usize, 0usize)];
+        is_outside[0][0] = true;
+        while let Some((r, c)) = stack.pop() {
+            let neighbors = [
+                (r.wrapping_sub(1), c),
+                (r + 1, c),
+                (r, c.wrapping_sub(1)),
+                (r, c + 1),
+            ];
+            for (nr, nc) in neighbors {
+                if nr < height && nc < width && !edge[nr][nc] && !is_outside[nr][nc] {
+                    is_outside[nr][nc] = true;
+                    stack.push((nr, nc));
+                }
+            }
+        }
+
+        // Weigh outside cells by the tiles they stand for: gaps between
+        // adjacent coordinates hold none
+        let tiles_in = |coords: &[i32], cell: usize| -> i64 {
+            if cell % 2 == 1 {
+                1
+            } else if cell > 0 && cell / 2 < coords.len() {
+                (coords[cell / 2] as i64 - coords[cell / 2 - 1] as i64 - 1).max(0)
+            } else {
+                0
+            }
+        };
+
+        let mut outside = vec![vec![0i64; width + 1]; height + 1];
+        for r in 0..height {
+            for c in 0..width {
+                let weight = if is_outside[r][c] {
+                    tiles_in(&ys, r) * tiles_in(&xs, c)
+                } else {
+                    0
+                };
+                outside[r + 1][c + 1] =
+                    weight + outside[r][c + 1] + outside[r + 1][c] - outside[r][c];
+            }
+        }
+
+        Ok(Floor { xs, ys, outside })
+    }
+
+    /// Whether the rectangle with red tiles `p1` and `p2` as opposite corners
+    /// holds only red or green tiles
+    pub fn is_valid_rectangle(&self, p1: Point, p2: Point) -> bool {
+        let (Ok(x1), Ok(x2)) = (self.xs.binary_search(&p1.x), self.xs.binary_search(&p2.x)) else {
+            return false;
+        };
+        let (Ok(y1), Ok(y2)) = (self.ys.binary_search(&p1.y), self.ys.binary_search(&p2.y)) else {
+            return false;
+        };
+
+        // Prefix sums are exclusive, so the last cell is 2i+1 inclusive
+        let (c1, c2) = (2 * x1.min(x2) + 1, 2 * x1.max(x2) + 2);
+        let (r1, r2) = (2 * y1.min(y2) + 1, 2 * y1.max(y2) + 2);
+        let outside = self.outside[r2][c2] - self.outside[r1][c2] - self.outside[r2][c1]
+            + self.outside[r1][c1];
+        outside == 0
+    }
+}
+
+/// Largest rectangle having two red tiles as opposite corners and only red or green tiles inside
+pub fn solve_part2(tiles: &[Point]) -> Result<i64> {
+    let floor = Floor::new(tiles)?;
+    let n = tiles.len();
+    let mut max_area = 0;
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let p1 = tiles[i];
+            let p2 = tiles[j];
+            let width = (p2.x as i64 - p1.x as i64).abs() + 1;
+            let height = (p2.y as i64 - p1.y as i64).abs() + 1;
+            let area = width * height;
+
+            if area > max_area && floor.is_valid_rectangle(p1, p2) {
+                max_area = area;
+            }
+        }
+    }
+
+    Ok(max_area)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    const EXAMPLE: &'static str = "\
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+";
+
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_tiles(input)
+    }
+
+    fn part1(tiles: &Self::Input) -> Result<Answer> {
+        Ok(solve_part1(tiles).into())
+    }
+
+    fn part2(tiles: &Self::Input) -> Result<Answer> {
+        Ok(solve_part2(tiles)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{InputSource, Part};
+    use std::collections::HashSet;
+
+    fn example(part: Part) -> Answer {
+        common::run::<Day9>(&InputSource::Example, part).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(Part::One), Answer::from(50));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(Part::Two), Answer::from(24));
+    }
+
+    /// Check if a point is inside a polygon using ray casting algorithm
+    fn point_in_polygon(point: &Point, polygon: &[Point]) -> bool {
+        let mut inside = false;
+        let n = polygon.len();
+
+        let mut j = n - 1;
+        for i in 0..n {
+            let pi = polygon[i];
+            let pj = polygon[j];
+
+            if ((pi.y > point.y) != (pj.y > point.y))
+                && (point.x < (pj.x - pi.x) * (point.y - pi.y) / (pj.y - pi.y) + pi.x)
+            {
+                inside = !inside;
+            }
+            j = i;
+        }
+
+        inside
+    }
+
+    /// Check if a point is on the edge between consecutive red tiles
+    fn is_on_edge(point: &Point, red_tiles: &[Point]) -> bool {
+        let n = red_tiles.len();
+
+        for i in 0..n {
+            let p1 = red_tiles[i];
+            let p2 = red_tiles[(i + 1) % n];
+
+            // Check if point is on the line segment between p1 and p2
+            if p1.x == p2.x && p1.x == point.x {
+                // Vertical line
+                let min_y = p1.y.min(p2.y);
+                let max_y = p1.y.max(p2.y);
+                if point.y >= min_y && point.y <= max_y {
+                    return true;
+                }
+            } else if p1.y == p2.y && p1.y == point.y {
+                // Horizontal line
+                let min_x = p1.x.min(p2.x);
+                let max_x = p1.x.max(p2.x);
+                if point.x >= min_x && point.x <= max_x {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Check if a point is red or green
+    fn is_valid_tile(point: &Point, red_tiles: &HashSet<Point>, polygon: &[Point]) -> bool {
+        // Check if it's a red tile
+        if red_tiles.contains(point) {
+            return true;
+        }
+
+        // Check if it's on an edge (green tile)
+        if is_on_edge(point, polygon) {
+            return true;
+        }
+
+        // Check if it's inside the polygon (green tile)
+        point_in_polygon(point, polygon)
+    }
+
+    /// Check every tile of every candidate rectangle
+    fn brute_force(tiles: &[Point]) -> i64 {
+        let red: HashSet<Point> = tiles.iter().copied().collect();
+        let mut max_area = 0;
+        for (i, &p1) in tiles.iter().enumerate() {
+            for &p2 in &tiles[i + 1..] {
+                let valid = (p1.x.min(p2.x)..=p1.x.max(p2.x)).all(|x| {
+                    (p1.y.min(p2.y)..=p1.y.max(p2.y))
+                        .all(|y| is_valid_tile(&Point { x, y }, &red, tiles))
+                });
+                let area = ((p1.x - p2.x).abs() as i64 + 1) * ((p1.y - p2.y).abs() as i64 + 1);
+                if valid {
+                    max_area = max_area.max(area);
+                }
+            }
+        }
+        max_area
+    }
+
+    fn tiles(points: &[(i32, i32)]) -> Vec<Point> {
+        points.iter().map(|&(x, y)| Point { x, y }).collect()
+    }
+
+    #[test]
+    fn thin_notch_is_excluded() {
+        // A square with a one tile wide slit down from the top edge, which
+        // sampling a few thousand points steps over
+        let square = tiles(&[
+            (0, 0),
+            (1000, 0),
+            (1000, 1000),
+            (501, 1000),
+            (501, 2),
+            (499, 2),
+            (499, 1000),
+            (0, 1000),
+        ]);
+        assert_eq!(solve_part2(&square).unwrap(), 500 * 1001);
+    }
+
+    #[test]
+    fn closed_slit_is_included() {
+        // Edges in adjacent columns leave no outside tile between them
+        let square = tiles(&[
+            (0, 0),
+            (10, 0),
+            (10, 10),
+            (6, 10),
+            (6, 3),
+            (5, 3),
+            (5, 10),
+            (0, 10),
+        ]);
+        assert_eq!(solve_part2(&square).unwrap(), 121);
+        assert_eq!(brute_force(&square), 121);
+    }
+
+    #[test]
+    fn matches_brute_force_on_small_shapes() {
+        let shapes = [
+            // Staircase
+            tiles(&[
+                (0, 0),
+                (8, 0),
+                (8, 2),
+                (6, 2),
+                (6, 4),
+                (4, 4),
+                (4, 6),
+                (0, 6),
+            ]),
+            // Notch cut into the side
+            tiles(&[
+                (0, 0),
+                (12, 0),
+                (12, 9),
+                (7, 9),
+                (7, 4),
+                (6, 4),
+                (6, 9),
+                (0, 9),
+            ]),
+            // Plus sign
+            tiles(&[
+                (3, 0),
+                (6, 0),
+                (6, 3),
+                (9, 3),
+                (9, 6),
+                (6, 6),
+                (6, 9),
+                (3, 9),
+                (3, 6),
+                (0, 6),
+                (0, 3),
+                (3, 3),
+            ]),
+            // Comb with narrow teeth
+            tiles(&[
+                (0, 0),
+                (2, 0),
+                (2, 8),
+                (3, 8),
+                (3, 0),
+                (5, 0),
+                (5, 8),
+                (7, 8),
+                (7, 10),
+                (0, 10),
+            ]),
+        ];
+        for shape in &shapes {
+            assert_eq!(
+                solve_part2(shape).unwrap(),
+                brute_force(shape),
+                "{:?}",
+                shape
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_diagonal_steps() {
+        let triangle = tiles(&[(0, 0), (5, 0), (0, 5)]);
+        assert!(matches!(solve_part2(&triangle), Err(Error::Unsolvable(_))));
+    }
+
+    #[test]
+    #[ignore = "needs the real input.txt and answers.toml"]
+    fn real_input() {
+        common::assert_real_input::<Day9>();
+    }
+}